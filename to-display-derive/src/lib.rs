@@ -1,25 +1,224 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
+use syn::ext::IdentExt;
 use syn::parse_macro_input;
+use syn::parse_quote;
+use syn::punctuated::Punctuated;
+use syn::Data;
 use syn::DeriveInput;
+use syn::Fields;
+use syn::Token;
 
-#[proc_macro_derive(ToDisplay)]
+/// Implement `ToDisplay` for a type.
+///
+/// By default the type must implement `Display`, which is used to display it, as a string in
+/// JSON.
+///
+/// With `#[to_display(fields)]`, a struct with named fields is displayed by its fields, which must
+/// all implement `ToDisplay`: `Foo { a: 1, b: x }`, or flattened into `a=1 b.c=x` in logfmt style.
+///
+/// With `#[to_display(fields, valuable)]`, the struct also implements `valuable::Valuable` and
+/// `valuable::Structable`, which requires the `valuable` feature of `to-display`, and fields that
+/// all implement `Valuable`.
+#[proc_macro_derive(ToDisplay, attributes(to_display))]
 pub fn derive_to_display(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
     let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = match parse_attrs(&input) {
+        Ok(attrs) if attrs.fields => {
+            derive_fields(&input, &attrs).unwrap_or_else(syn::Error::into_compile_error)
+        }
+        Ok(_) => derive_display(&input),
+        Err(e) => e.into_compile_error(),
+    };
+
+    // Convert back to token stream and return
+    TokenStream::from(expanded)
+}
+
+/// The options of `#[to_display(...)]`.
+#[derive(Default)]
+struct Attrs {
+    /// `fields`: display a struct by its fields.
+    fields: bool,
+    /// `valuable`: also implement `valuable::Valuable` for a struct displayed by its fields.
+    valuable: bool,
+}
+
+/// Parse the `#[to_display(...)]` attributes.
+fn parse_attrs(input: &DeriveInput) -> syn::Result<Attrs> {
+    let mut attrs = Attrs::default();
+    let mut valuable_path = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("to_display"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("fields") {
+                attrs.fields = true;
+                Ok(())
+            } else if meta.path.is_ident("valuable") {
+                attrs.valuable = true;
+                valuable_path = Some(meta.path.clone());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported to_display attribute, expected `fields` or `valuable`"))
+            }
+        })?;
+    }
+
+    if let Some(path) = valuable_path {
+        if !attrs.fields {
+            return Err(syn::Error::new_spanned(
+                path,
+                "#[to_display(valuable)] requires #[to_display(fields)]",
+            ));
+        }
+    }
+
+    Ok(attrs)
+}
+
+/// Display the type with its `Display` implementation.
+fn derive_display(input: &DeriveInput) -> TokenStream2 {
     let name = &input.ident;
 
-    // Generate the implementation
-    let expanded = quote! {
+    quote! {
         impl ::to_display::ToDisplay for #name {
-            type Displayer<'a> = &'a #name where Self: 'a;
+            type Displayer<'a> = ::to_display::DisplayText<'a, #name> where Self: 'a;
 
             fn display_with_context(&self, context: ::to_display::Context) -> Self::Displayer<'_> {
-                self
+                ::to_display::DisplayText::new(self, context)
+            }
+        }
+    }
+}
+
+/// Display a struct by visiting its named fields.
+fn derive_fields(input: &DeriveInput, attrs: &Attrs) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "#[to_display(fields)] requires a struct with named fields",
+                ))
             }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "#[to_display(fields)] can only be used on a struct",
+            ))
         }
     };
 
-    // Convert back to token stream and return
-    TokenStream::from(expanded)
+    let mut generics = input.generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        for field in fields {
+            let ty = &field.ty;
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: ::to_display::ToDisplay));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let idents = fields.iter().map(|f| f.ident.as_ref().unwrap());
+    // Display `r#type` as `type`, the same as serde.
+    let names = idents.clone().map(|i| i.unraw().to_string());
+    let type_name = name.unraw().to_string();
+
+    let valuable = if attrs.valuable {
+        derive_valuable(input, fields)
+    } else {
+        TokenStream2::new()
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::to_display::ToDisplay for #name #ty_generics #where_clause {
+            type Displayer<'a> = ::to_display::DisplayStruct<'a, Self> where Self: 'a;
+
+            fn display_with_context(&self, context: ::to_display::Context) -> Self::Displayer<'_> {
+                ::to_display::DisplayStruct::new(self, #type_name, context)
+            }
+
+            fn visit_fields(
+                &self,
+                visitor: &mut dyn ::to_display::FieldVisitor,
+                context: ::to_display::Context,
+            ) -> ::core::option::Option<::core::fmt::Result> {
+                let _ = context;
+                let mut visit = || -> ::core::fmt::Result {
+                    #( visitor.visit(&#names, &self.#idents)?; )*
+                    ::core::result::Result::Ok(())
+                };
+                ::core::option::Option::Some(visit())
+            }
+        }
+
+        #valuable
+    })
+}
+
+/// Implement `valuable::Valuable` and `valuable::Structable` for a struct with named fields.
+fn derive_valuable(
+    input: &DeriveInput,
+    fields: &Punctuated<syn::Field, Token![,]>,
+) -> TokenStream2 {
+    let name = &input.ident;
+
+    let mut generics = input.generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        for field in fields {
+            let ty = &field.ty;
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: ::to_display::valuable::Valuable));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let idents = fields.iter().map(|f| f.ident.as_ref().unwrap());
+    let names = idents.clone().map(|i| i.unraw().to_string());
+    let type_name = name.unraw().to_string();
+
+    quote! {
+        const _: () = {
+            static FIELDS: &[::to_display::valuable::NamedField<'static>] = &[
+                #( ::to_display::valuable::NamedField::new(#names), )*
+            ];
+
+            impl #impl_generics ::to_display::valuable::Valuable for #name #ty_generics #where_clause {
+                fn as_value(&self) -> ::to_display::valuable::Value<'_> {
+                    ::to_display::valuable::Value::Structable(self)
+                }
+
+                fn visit(&self, visitor: &mut dyn ::to_display::valuable::Visit) {
+                    visitor.visit_named_fields(&::to_display::valuable::NamedValues::new(
+                        FIELDS,
+                        &[ #( ::to_display::valuable::Valuable::as_value(&self.#idents), )* ],
+                    ));
+                }
+            }
+
+            impl #impl_generics ::to_display::valuable::Structable for #name #ty_generics #where_clause {
+                fn definition(&self) -> ::to_display::valuable::StructDef<'_> {
+                    ::to_display::valuable::StructDef::new_static(
+                        #type_name,
+                        ::to_display::valuable::Fields::Named(FIELDS),
+                    )
+                }
+            }
+        };
+    }
 }
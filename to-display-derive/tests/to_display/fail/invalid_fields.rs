@@ -0,0 +1,19 @@
+#[derive(to_display_derive::ToDisplay)]
+#[to_display(fields)]
+enum Foo {
+    A,
+}
+
+#[derive(to_display_derive::ToDisplay)]
+#[to_display(fields)]
+struct Bar(u64);
+
+#[derive(to_display_derive::ToDisplay)]
+#[to_display(foo)]
+struct Baz {}
+
+#[derive(to_display_derive::ToDisplay)]
+#[to_display(valuable)]
+struct Qux {}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#[to_display(fields)]
+struct Foo<T> {
+    a: u64,
+    b: Option<T>,
+}
+impl<T> ::to_display::ToDisplay for Foo<T>
+where
+    u64: ::to_display::ToDisplay,
+    Option<T>: ::to_display::ToDisplay,
+{
+    type Displayer<'a> = ::to_display::DisplayStruct<'a, Self> where Self: 'a;
+    fn display_with_context(
+        &self,
+        context: ::to_display::Context,
+    ) -> Self::Displayer<'_> {
+        ::to_display::DisplayStruct::new(self, "Foo", context)
+    }
+    fn visit_fields(
+        &self,
+        visitor: &mut dyn ::to_display::FieldVisitor,
+        context: ::to_display::Context,
+    ) -> ::core::option::Option<::core::fmt::Result> {
+        let _ = context;
+        let mut visit = || -> ::core::fmt::Result {
+            visitor.visit(&"a", &self.a)?;
+            visitor.visit(&"b", &self.b)?;
+            ::core::result::Result::Ok(())
+        };
+        ::core::option::Option::Some(visit())
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(to_display_derive::ToDisplay)]
+#[to_display(fields)]
+struct Foo<T> {
+    a: u64,
+    b: Option<T>,
+}
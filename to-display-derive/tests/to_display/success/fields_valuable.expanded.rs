@@ -0,0 +1,70 @@
+#[to_display(fields, valuable)]
+struct Foo<T> {
+    a: u64,
+    b: Option<T>,
+}
+impl<T> ::to_display::ToDisplay for Foo<T>
+where
+    u64: ::to_display::ToDisplay,
+    Option<T>: ::to_display::ToDisplay,
+{
+    type Displayer<'a> = ::to_display::DisplayStruct<'a, Self> where Self: 'a;
+    fn display_with_context(
+        &self,
+        context: ::to_display::Context,
+    ) -> Self::Displayer<'_> {
+        ::to_display::DisplayStruct::new(self, "Foo", context)
+    }
+    fn visit_fields(
+        &self,
+        visitor: &mut dyn ::to_display::FieldVisitor,
+        context: ::to_display::Context,
+    ) -> ::core::option::Option<::core::fmt::Result> {
+        let _ = context;
+        let mut visit = || -> ::core::fmt::Result {
+            visitor.visit(&"a", &self.a)?;
+            visitor.visit(&"b", &self.b)?;
+            ::core::result::Result::Ok(())
+        };
+        ::core::option::Option::Some(visit())
+    }
+}
+const _: () = {
+    static FIELDS: &[::to_display::valuable::NamedField<'static>] = &[
+        ::to_display::valuable::NamedField::new("a"),
+        ::to_display::valuable::NamedField::new("b"),
+    ];
+    impl<T> ::to_display::valuable::Valuable for Foo<T>
+    where
+        u64: ::to_display::valuable::Valuable,
+        Option<T>: ::to_display::valuable::Valuable,
+    {
+        fn as_value(&self) -> ::to_display::valuable::Value<'_> {
+            ::to_display::valuable::Value::Structable(self)
+        }
+        fn visit(&self, visitor: &mut dyn ::to_display::valuable::Visit) {
+            visitor
+                .visit_named_fields(
+                    &::to_display::valuable::NamedValues::new(
+                        FIELDS,
+                        &[
+                            ::to_display::valuable::Valuable::as_value(&self.a),
+                            ::to_display::valuable::Valuable::as_value(&self.b),
+                        ],
+                    ),
+                );
+        }
+    }
+    impl<T> ::to_display::valuable::Structable for Foo<T>
+    where
+        u64: ::to_display::valuable::Valuable,
+        Option<T>: ::to_display::valuable::Valuable,
+    {
+        fn definition(&self) -> ::to_display::valuable::StructDef<'_> {
+            ::to_display::valuable::StructDef::new_static(
+                "Foo",
+                ::to_display::valuable::Fields::Named(FIELDS),
+            )
+        }
+    }
+};
@@ -0,0 +1,50 @@
+/// How a byte slice `[u8]` or `Vec<u8>` is formatted.
+///
+/// Set with [`DisplayConfig::with_byte_format()`] or one of its shortcuts. In every format,
+/// at most [`max_items`](crate::Context::max_items) bytes are displayed.
+///
+/// # Example:
+/// ```
+/// use to_display::ByteFormat;
+/// use to_display::DisplayConfig;
+/// use to_display::ToDisplay;
+///
+/// assert_eq!(ByteFormat::default(), ByteFormat::List);
+///
+/// let b: &[u8] = b"ab\0";
+/// assert_eq!(b.display().to_string(), "[97, 98, 0]");
+/// assert_eq!(b.display().use_hex_bytes().to_string(), "616200");
+/// assert_eq!(b.display().use_escaped_bytes().to_string(), r#"b"ab\x00""#);
+/// assert_eq!(b.display().use_base64_bytes().to_string(), "YWIA");
+///
+/// let b: &[u8] = b"\xab\xcd";
+/// assert_eq!(b.display().use_hex_bytes().to_string(), "abcd");
+/// assert_eq!(b.display().use_upper_hex_bytes().to_string(), "ABCD");
+/// ```
+///
+/// [`DisplayConfig::with_byte_format()`]: crate::DisplayConfig::with_byte_format
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteFormat {
+    /// A list of decimal numbers, the same as other slices: `[97, 98, 0]`.
+    #[default]
+    List,
+
+    /// Lowercase hex: `616200`.
+    Hex,
+
+    /// Uppercase hex: `616200`, or `ABCD` for `[0xab, 0xcd]`.
+    UpperHex,
+
+    /// A Rust byte string literal: `b"ab\x00"`.
+    Escaped,
+
+    /// Standard base64 with padding: `YWIA`.
+    Base64,
+
+    /// Multi-line hexdump with offsets, 16 bytes per line:
+    ///
+    /// ```text
+    /// 00000000  61 62 00                                          |ab.|
+    /// ```
+    HexDump,
+}
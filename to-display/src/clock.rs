@@ -0,0 +1,153 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use crate::time_backend;
+use crate::UtcOffset;
+
+/// Source of "now" and of the local timezone used when formatting time values.
+///
+/// An [`Instant`] has no absolute value, so it is converted to wall-clock time by comparing it
+/// with the current time of a clock. The default [`SystemClock`] reads the real system clocks;
+/// [`FixedClock`] returns a fixed moment and offset, which makes the output deterministic in
+/// tests.
+///
+/// A clock is installed on a displayer with [`DisplayConfig::with_clock()`], or on the current
+/// thread with [`scoped_clock()`].
+///
+/// [`DisplayConfig::with_clock()`]: crate::DisplayConfig::with_clock
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current monotonic time and the wall-clock time of the same moment.
+    fn now(&self) -> (Instant, SystemTime);
+
+    /// Returns the offset of the local timezone at the given time.
+    fn local_offset(&self, at: SystemTime) -> UtcOffset;
+}
+
+/// A [`Clock`] that reads the system clocks and the system local timezone.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> (Instant, SystemTime) {
+        (Instant::now(), SystemTime::now())
+    }
+
+    fn local_offset(&self, at: SystemTime) -> UtcOffset {
+        // The offset of a real timezone is always in range.
+        UtcOffset::from_seconds(time_backend::local_offset(at)).unwrap_or(UtcOffset::UTC)
+    }
+}
+
+/// A [`Clock`] that always returns the same moment and local timezone.
+///
+/// # Example:
+/// ```
+/// use std::time::Duration;
+/// use std::time::Instant;
+/// use std::time::UNIX_EPOCH;
+///
+/// use to_display::scoped_clock;
+/// use to_display::DisplayConfig;
+/// use to_display::FixedClock;
+/// use to_display::ToDisplay;
+///
+/// let now = Instant::now();
+/// let _clock = scoped_clock(FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(3600)));
+///
+/// assert_eq!(now.display().use_utc_time().to_string(), "01:00:00.000000");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock {
+    instant: Instant,
+    system_time: SystemTime,
+    local_offset: UtcOffset,
+}
+
+impl FixedClock {
+    /// Create a clock whose current time is `instant`, corresponding to the wall-clock time
+    /// `system_time`. The local timezone is UTC.
+    pub fn new(instant: Instant, system_time: SystemTime) -> Self {
+        Self {
+            instant,
+            system_time,
+            local_offset: UtcOffset::UTC,
+        }
+    }
+
+    /// Set the offset of the local timezone.
+    pub fn with_local_offset(mut self, local_offset: UtcOffset) -> Self {
+        self.local_offset = local_offset;
+        self
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> (Instant, SystemTime) {
+        (self.instant, self.system_time)
+    }
+
+    fn local_offset(&self, _at: SystemTime) -> UtcOffset {
+        self.local_offset
+    }
+}
+
+std::thread_local! {
+    /// The clock installed on this thread with [`scoped_clock()`].
+    static SCOPED: RefCell<Option<Rc<dyn Clock>>> = const { RefCell::new(None) };
+}
+
+/// Install `clock` on the current thread until the returned guard is dropped.
+///
+/// Time values displayed on this thread use it, unless another clock is set in their
+/// [`Context`](crate::Context) with [`DisplayConfig::with_clock()`]. Unlike `with_clock()`, it
+/// takes the clock by value, so a [`FixedClock`] built in a test does not have to be leaked.
+///
+/// Guards can be nested: dropping one restores the clock that was installed before it.
+///
+/// [`DisplayConfig::with_clock()`]: crate::DisplayConfig::with_clock
+pub fn scoped_clock(clock: impl Clock + 'static) -> ClockGuard {
+    let previous = SCOPED.with(|scoped| scoped.replace(Some(Rc::new(clock))));
+    ClockGuard { previous }
+}
+
+/// Restores the previous clock of the current thread when dropped.
+///
+/// This is the return value of calling [`scoped_clock()`]. It is not `Send`, since the clock is
+/// installed on the thread that created it.
+#[must_use = "the clock is uninstalled when the guard is dropped"]
+pub struct ClockGuard {
+    previous: Option<Rc<dyn Clock>>,
+}
+
+impl fmt::Debug for ClockGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClockGuard").finish_non_exhaustive()
+    }
+}
+
+impl Drop for ClockGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        // The thread local may already be destroyed if the guard is dropped in another one.
+        let _ = SCOPED.try_with(|scoped| scoped.replace(previous));
+    }
+}
+
+/// Call `f` with `clock`, or else the clock installed on this thread, or else [`SystemClock`].
+pub(crate) fn with_clock<R>(
+    clock: Option<&'static dyn Clock>,
+    f: impl FnOnce(&dyn Clock) -> R,
+) -> R {
+    if let Some(clock) = clock {
+        return f(clock);
+    }
+
+    // Clone it out of the cell, so that `f` can display values that install a clock themselves.
+    match SCOPED.with(|scoped| scoped.borrow().clone()) {
+        Some(clock) => f(&*clock),
+        None => f(&SystemClock),
+    }
+}
@@ -0,0 +1,223 @@
+//! Writes the elements of lists, maps, structs and tuples: brackets, separators, truncation and
+//! keys, in the plain style or as JSON.
+//!
+//! Shared by [`fmt_list()`](crate::display_slice::fmt_list), `fmt_map()`,
+//! [`DisplayStruct`](crate::DisplayStruct) and the `serde` and `valuable` bridges.
+
+use core::fmt;
+
+use crate::json;
+use crate::Context;
+
+/// The kind of a compound value, which decides its brackets and how its elements are separated
+/// and truncated.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    /// A list, truncated by `max_items`.
+    List,
+    /// A map, truncated by `max_items`.
+    Map,
+    /// A struct, whose fields may be redacted.
+    Struct,
+    /// A tuple, which is not truncated.
+    #[cfg(any(feature = "serde", feature = "valuable"))]
+    Tuple,
+}
+
+impl Kind {
+    fn brackets(self, context: Context) -> (&'static str, &'static str) {
+        match (self, context.is_json()) {
+            (Kind::List, true) => ("[", "]"),
+            (Kind::Map | Kind::Struct, true) => ("{", "}"),
+            (Kind::List, false) => context.list_brackets(),
+            (Kind::Map, false) => context.map_brackets(),
+            (Kind::Struct, false) => (" {", "}"),
+            #[cfg(any(feature = "serde", feature = "valuable"))]
+            (Kind::Tuple, true) => ("[", "]"),
+            #[cfg(any(feature = "serde", feature = "valuable"))]
+            (Kind::Tuple, false) => ("(", ")"),
+        }
+    }
+}
+
+/// Writes the elements of a compound value between its brackets.
+///
+/// Elements beyond `max_items` of a list or a map are replaced with a single `...`.
+pub(crate) struct Elements {
+    kind: Kind,
+    context: Context,
+    close: &'static str,
+    /// Whether the value is wrapped in a `{"name":...}` JSON object, for an enum variant.
+    wrapped: bool,
+    /// The number of elements, entries or fields seen so far.
+    len: usize,
+}
+
+impl Elements {
+    /// Write the opening bracket of a compound value.
+    ///
+    /// `prefix` is the name of an enum variant, or of a struct or tuple struct in plain style. In
+    /// JSON, the value is wrapped in an object whose only key is `prefix`.
+    pub(crate) fn begin(
+        f: &mut fmt::Formatter<'_>,
+        kind: Kind,
+        context: Context,
+        prefix: Option<&str>,
+    ) -> Result<Self, fmt::Error> {
+        let (open, close) = kind.brackets(context);
+        let wrapped = prefix.is_some() && context.is_json();
+
+        if let Some(prefix) = prefix {
+            if wrapped {
+                f.write_str("{")?;
+                json::fmt_json_string(f, prefix)?;
+                f.write_str(":")?;
+            } else {
+                f.write_str(prefix)?;
+            }
+        }
+        f.write_str(open)?;
+
+        Ok(Elements {
+            kind,
+            context,
+            close,
+            wrapped,
+            len: 0,
+        })
+    }
+
+    fn separator(&self) -> &'static str {
+        if self.context.is_json() {
+            ","
+        } else if self.kind == Kind::Struct {
+            ", "
+        } else {
+            self.context.separator()
+        }
+    }
+
+    /// Count an element and write the separator before it.
+    ///
+    /// Returns `false` if the element is not to be written, because it is beyond `max_items`.
+    /// The first element beyond `max_items` is replaced with `...`.
+    pub(crate) fn next(&mut self, f: &mut fmt::Formatter<'_>) -> Result<bool, fmt::Error> {
+        let i = self.len;
+        self.len += 1;
+
+        let context = self.context;
+        let limited = matches!(self.kind, Kind::List | Kind::Map);
+
+        if limited && i >= context.max_items() {
+            if i == context.max_items() {
+                // A JSON array or object can not start with a separator.
+                if i > 0 || !context.is_json() {
+                    f.write_str(self.separator())?;
+                }
+
+                if !context.is_json() {
+                    f.write_str("...")?;
+                } else if self.kind == Kind::Map {
+                    f.write_str("\"...\":\"...\"")?;
+                } else {
+                    f.write_str("\"...\"")?;
+                }
+            }
+            return Ok(false);
+        }
+
+        if i > 0 {
+            f.write_str(self.separator())?;
+        } else if self.kind == Kind::Struct && !context.is_json() {
+            f.write_str(" ")?;
+        }
+        Ok(true)
+    }
+
+    /// Count a map entry and write its key and the key-value separator.
+    ///
+    /// `key` builds the displayer of the key with the context it is to be written with. Returns
+    /// `false` if the value is not to be written, see [`next()`](Self::next).
+    #[cfg(any(feature = "alloc", feature = "valuable"))]
+    pub(crate) fn key<D>(
+        &mut self,
+        f: &mut fmt::Formatter<'_>,
+        key: impl FnOnce(Context) -> D,
+    ) -> Result<bool, fmt::Error>
+    where
+        D: fmt::Display,
+    {
+        if !self.next(f)? {
+            return Ok(false);
+        }
+
+        let context = self.context;
+        if context.is_json() {
+            // JSON keys are strings: write the key in plain style, inside a JSON string.
+            json::fmt_json_string(f, key(json::plain(context.key_context())))?;
+            f.write_str(":")?;
+        } else {
+            write!(
+                f,
+                "{}{}",
+                key(context.key_context()),
+                context.key_value_separator()
+            )?;
+        }
+        Ok(true)
+    }
+
+    /// Count a struct field and write its name.
+    ///
+    /// Returns `false` if the value is not to be written, see [`next()`](Self::next).
+    pub(crate) fn field_name(
+        &mut self,
+        f: &mut fmt::Formatter<'_>,
+        name: impl fmt::Display,
+    ) -> Result<bool, fmt::Error> {
+        if !self.next(f)? {
+            return Ok(false);
+        }
+
+        if self.context.is_json() {
+            json::fmt_json_string(f, name)?;
+            f.write_str(":")?;
+        } else {
+            write!(f, "{}: ", name)?;
+        }
+        Ok(true)
+    }
+
+    /// Count a struct field and write its name.
+    ///
+    /// Returns `false` if the value is not to be written: it is beyond `max_items`, or the field
+    /// is redacted and its value is replaced with `<redacted>`.
+    #[cfg(any(feature = "serde", feature = "valuable"))]
+    pub(crate) fn field(
+        &mut self,
+        f: &mut fmt::Formatter<'_>,
+        name: &str,
+    ) -> Result<bool, fmt::Error> {
+        if !self.field_name(f, name)? {
+            return Ok(false);
+        }
+
+        if self.context.is_redacted(name) {
+            json::fmt_str(f, self.context, "<redacted>")?;
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Write the closing bracket.
+    pub(crate) fn end(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.kind == Kind::Struct && !self.context.is_json() && self.len > 0 {
+            f.write_str(" ")?;
+        }
+        f.write_str(self.close)?;
+        if self.wrapped {
+            f.write_str("}")?;
+        }
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
-#[derive(Default, Clone, Debug, Copy, PartialEq, Eq)]
-pub enum LocalOrUTC {
-    #[default]
-    Local,
-    Utc,
-}
+use core::sync::atomic::Ordering;
+
+#[cfg(feature = "_time")]
+use crate::clock::Clock;
+use crate::ByteFormat;
+use crate::OutputStyle;
+use crate::RangeFormat;
+use crate::TimeFormat;
+use crate::Timezone;
 
 /// Configuration that controls how values are formatted.
 ///
@@ -13,14 +16,33 @@ pub enum LocalOrUTC {
 /// This struct is used by the [`Display`] implementation to get the current formatting
 /// specification.
 ///
-/// [`Display`]: std::fmt::Display
+/// [`Display`]: core::fmt::Display
 /// [`ToDisplay::display_with_context()`]: crate::ToDisplay::display_with_context
 #[derive(Default, Clone, Debug, Copy)]
 pub struct Context {
     pub(crate) verbose: Option<bool>,
+    pub(crate) output_style: Option<OutputStyle>,
     pub(crate) max_items: Option<usize>,
-    pub(crate) local_or_utc: Option<LocalOrUTC>,
-    pub(crate) time_format: Option<&'static str>,
+    pub(crate) bare_ok: Option<bool>,
+    pub(crate) backtrace: Option<bool>,
+    pub(crate) quote_paths: Option<bool>,
+    pub(crate) escape_non_utf8: Option<bool>,
+    pub(crate) max_path_len: Option<usize>,
+    pub(crate) escape_strings: Option<bool>,
+    pub(crate) max_str_len: Option<usize>,
+    pub(crate) byte_format: Option<ByteFormat>,
+    pub(crate) atomic_ordering: Option<Ordering>,
+    pub(crate) range_format: Option<RangeFormat>,
+    pub(crate) separator: Option<&'static str>,
+    pub(crate) list_brackets: Option<(&'static str, &'static str)>,
+    pub(crate) map_brackets: Option<(&'static str, &'static str)>,
+    pub(crate) key_value_separator: Option<&'static str>,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) redacted_fields: Option<&'static [&'static str]>,
+    pub(crate) timezone: Option<Timezone>,
+    pub(crate) time_format: Option<TimeFormat>,
+    #[cfg(feature = "_time")]
+    pub(crate) clock: Option<&'static dyn Clock>,
 }
 
 impl Context {
@@ -34,6 +56,18 @@ impl Context {
         self.verbose.unwrap_or(false)
     }
 
+    /// Returns the style of the whole output tree.
+    ///
+    /// Defaults to [`OutputStyle::Plain`].
+    pub fn output_style(&self) -> OutputStyle {
+        self.output_style.unwrap_or_default()
+    }
+
+    /// Returns whether the output style is [`OutputStyle::Json`].
+    pub fn is_json(&self) -> bool {
+        self.output_style() == OutputStyle::Json
+    }
+
     /// Returns the maximum number of items to display for collections.
     ///
     /// This affects the formatting of slices, vectors, maps, and similar collections.
@@ -44,31 +78,201 @@ impl Context {
         self.max_items.unwrap_or(32)
     }
 
+    /// Returns the separator between the items of a list or the entries of a map.
+    ///
+    /// Defaults to `", "`.
+    pub fn separator(&self) -> &'static str {
+        self.separator.unwrap_or(", ")
+    }
+
+    /// Returns the opening and closing brackets of a list.
+    ///
+    /// Defaults to `("[", "]")`.
+    pub fn list_brackets(&self) -> (&'static str, &'static str) {
+        self.list_brackets.unwrap_or(("[", "]"))
+    }
+
+    /// Returns the opening and closing brackets of a map.
+    ///
+    /// Defaults to `("{", "}")`.
+    pub fn map_brackets(&self) -> (&'static str, &'static str) {
+        self.map_brackets.unwrap_or(("{", "}"))
+    }
+
+    /// Returns the separator between the key and the value of a map entry.
+    ///
+    /// Defaults to `": "`.
+    pub fn key_value_separator(&self) -> &'static str {
+        self.key_value_separator.unwrap_or(": ")
+    }
+
+    /// Returns the maximum depth of nested sequences, maps and structs of a value displayed with
+    /// `display_serde()` or `display_valuable()`. Deeper ones are displayed as `...`.
+    ///
+    /// Defaults to no limit.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth.unwrap_or(usize::MAX)
+    }
+
+    /// Returns the names of the struct fields whose value is displayed as `<redacted>` by
+    /// `display_serde()` or `display_valuable()`.
+    ///
+    /// Defaults to no fields.
+    pub fn redacted_fields(&self) -> &'static [&'static str] {
+        self.redacted_fields.unwrap_or(&[])
+    }
+
+    /// Returns whether the value of the struct field `name` is redacted.
+    pub fn is_redacted(&self, name: &str) -> bool {
+        self.redacted_fields().contains(&name)
+    }
+
+    /// Returns whether a `Result::Ok` is displayed as `v` instead of `Ok(v)` when not verbose.
+    ///
+    /// Defaults to `false`.
+    pub fn bare_ok(&self) -> bool {
+        self.bare_ok.unwrap_or(false)
+    }
+
+    /// Returns whether a captured backtrace of an error is displayed.
+    ///
+    /// Defaults to `false`.
+    pub fn backtrace(&self) -> bool {
+        self.backtrace.unwrap_or(false)
+    }
+
+    /// Returns whether paths and OS strings are wrapped in double quotes.
+    ///
+    /// Defaults to `false`.
+    pub fn quote_paths(&self) -> bool {
+        self.quote_paths.unwrap_or(false)
+    }
+
+    /// Returns whether invalid UTF-8 in paths and OS strings is escaped as `\xNN` instead of
+    /// being replaced with `U+FFFD`.
+    ///
+    /// Defaults to `false`.
+    pub fn escape_non_utf8(&self) -> bool {
+        self.escape_non_utf8.unwrap_or(false)
+    }
+
+    /// Returns the maximum number of chars of a path before it is shortened in the middle.
+    ///
+    /// Defaults to no limit.
+    pub fn max_path_len(&self) -> usize {
+        self.max_path_len.unwrap_or(usize::MAX)
+    }
+
+    /// Returns whether strings and chars are quoted and escaped the same way as `Debug`, such as
+    /// `"a\u{1b}"` or `'\n'`.
+    ///
+    /// Defaults to `false`.
+    pub fn escape_strings(&self) -> bool {
+        self.escape_strings.unwrap_or(false)
+    }
+
+    /// Returns the maximum number of chars of a string before it is truncated and followed by
+    /// `...`.
+    ///
+    /// Defaults to no limit.
+    pub fn max_str_len(&self) -> usize {
+        self.max_str_len.unwrap_or(usize::MAX)
+    }
+
+    /// Returns the context to display a map key with: keys are never truncated.
+    #[cfg(any(feature = "alloc", feature = "valuable"))]
+    pub(crate) fn key_context(self) -> Context {
+        Context {
+            max_str_len: None,
+            ..self
+        }
+    }
+
+    /// Returns this context with the options that are not set taken from `default`.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn or(self, default: Context) -> Context {
+        Context {
+            verbose: self.verbose.or(default.verbose),
+            output_style: self.output_style.or(default.output_style),
+            max_items: self.max_items.or(default.max_items),
+            bare_ok: self.bare_ok.or(default.bare_ok),
+            backtrace: self.backtrace.or(default.backtrace),
+            quote_paths: self.quote_paths.or(default.quote_paths),
+            escape_non_utf8: self.escape_non_utf8.or(default.escape_non_utf8),
+            max_path_len: self.max_path_len.or(default.max_path_len),
+            escape_strings: self.escape_strings.or(default.escape_strings),
+            max_str_len: self.max_str_len.or(default.max_str_len),
+            byte_format: self.byte_format.or(default.byte_format),
+            atomic_ordering: self.atomic_ordering.or(default.atomic_ordering),
+            range_format: self.range_format.or(default.range_format),
+            separator: self.separator.or(default.separator),
+            list_brackets: self.list_brackets.or(default.list_brackets),
+            map_brackets: self.map_brackets.or(default.map_brackets),
+            key_value_separator: self.key_value_separator.or(default.key_value_separator),
+            max_depth: self.max_depth.or(default.max_depth),
+            redacted_fields: self.redacted_fields.or(default.redacted_fields),
+            timezone: self.timezone.or(default.timezone),
+            time_format: self.time_format.or(default.time_format),
+            #[cfg(feature = "_time")]
+            clock: self.clock.or(default.clock),
+        }
+    }
+
+    /// Returns the format used for byte slices.
+    ///
+    /// Defaults to [`ByteFormat::List`]: `[97, 98, 0]`.
+    pub fn byte_format(&self) -> ByteFormat {
+        self.byte_format.unwrap_or_default()
+    }
+
+    /// Returns the memory ordering used to load the value of an atomic.
+    ///
+    /// `Release` is loaded as `Relaxed` and `AcqRel` as `Acquire`, since they are not valid for a
+    /// load.
+    ///
+    /// Defaults to [`Ordering::SeqCst`].
+    pub fn atomic_ordering(&self) -> Ordering {
+        self.atomic_ordering.unwrap_or(Ordering::SeqCst)
+    }
+
+    /// Returns the format used for ranges and pairs of bounds.
+    ///
+    /// Defaults to [`RangeFormat::Interval`]: `[3, 7)`.
+    pub fn range_format(&self) -> RangeFormat {
+        self.range_format.unwrap_or_default()
+    }
+
+    /// Returns the timezone in which times are displayed.
+    ///
+    /// Defaults to [`Timezone::Local`].
+    pub fn timezone(&self) -> Timezone {
+        self.timezone.unwrap_or_default()
+    }
+
     /// Returns whether times should be displayed in local time.
     pub fn is_local_time(&self) -> bool {
-        self.local_or_utc.unwrap_or_default() == LocalOrUTC::Local
+        self.timezone() == Timezone::Local
     }
 
     /// Returns whether times should be displayed in UTC.
     pub fn is_utc_time(&self) -> bool {
-        self.local_or_utc.unwrap_or_default() == LocalOrUTC::Utc
+        self.timezone() == Timezone::Utc
     }
 
-    /// Returns the time format string used for formatting timestamps.
+    /// Returns the time format used for formatting timestamps.
     ///
-    /// Format options:
-    /// - Default/`"SIMPLE"`: `"%H:%M:%S%.6f"` (e.g., "23:59:59.123456")
-    /// - `"FULL"`: `"%Y-%m-%dT%H:%M:%S%.6fZ%z"` (e.g., "2024-12-28T23:59:59.123456Z+0800")
-    /// - Custom format string using [strftime format specifiers](https://docs.rs/chrono/latest/chrono/format/strftime/index.html)
-    pub fn time_format(&self) -> &'static str {
-        const SIMPLE_FORMAT: &str = "%H:%M:%S%.6f";
-        const FULL_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.6fZ%z";
+    /// Defaults to [`TimeFormat::Simple`]: `"%H:%M:%S%.6f"` (e.g., "23:59:59.123456").
+    pub fn time_format(&self) -> TimeFormat {
+        self.time_format.unwrap_or_default()
+    }
 
-        match self.time_format {
-            Some("SIMPLE") => SIMPLE_FORMAT,
-            Some("FULL") => FULL_FORMAT,
-            Some(format) => format,
-            None => SIMPLE_FORMAT,
-        }
+    /// Returns the clock set to convert time values to wall-clock time, if any.
+    ///
+    /// When it is not set, the clock installed on the current thread with
+    /// [`scoped_clock()`](crate::scoped_clock) is used, or else
+    /// [`SystemClock`](crate::SystemClock).
+    #[cfg(feature = "_time")]
+    pub fn clock(&self) -> Option<&'static dyn Clock> {
+        self.clock
     }
 }
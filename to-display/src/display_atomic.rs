@@ -0,0 +1,103 @@
+use core::fmt;
+use core::sync::atomic;
+use core::sync::atomic::Ordering;
+
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays an atomic type from `core::sync::atomic`, such as `AtomicU64`.
+///
+/// This is the return value of calling a `AtomicU64::display()`.
+///
+/// The value is loaded with the [`atomic_ordering`](crate::Context::atomic_ordering) in the
+/// [`Context`]. An `AtomicPtr` is displayed as an address: `0x7ffd5e8c`.
+pub struct DisplayAtomic<'a, A> {
+    atomic: &'a A,
+    context: Context,
+}
+
+impl<A> DisplayConfig for DisplayAtomic<'_, A> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+/// Convert an ordering to one that is valid for a load.
+fn load_ordering(ordering: Ordering) -> Ordering {
+    match ordering {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        other => other,
+    }
+}
+
+macro_rules! impl_to_display_atomic {
+    ($($width:literal: $t:ident),* $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl fmt::Display for DisplayAtomic<'_, atomic::$t> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let ordering = load_ordering(self.context.atomic_ordering());
+                    fmt::Display::fmt(&self.atomic.load(ordering), f)
+                }
+            }
+
+            #[cfg(target_has_atomic = $width)]
+            impl ToDisplay for atomic::$t {
+                type Displayer<'a> = DisplayAtomic<'a, Self>;
+
+                fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+                    DisplayAtomic {
+                        atomic: self,
+                        context,
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_to_display_atomic!(
+    "8": AtomicBool,
+    "8": AtomicI8,
+    "16": AtomicI16,
+    "32": AtomicI32,
+    "64": AtomicI64,
+    "ptr": AtomicIsize,
+    "8": AtomicU8,
+    "16": AtomicU16,
+    "32": AtomicU32,
+    "64": AtomicU64,
+    "ptr": AtomicUsize,
+);
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> fmt::Display for DisplayAtomic<'_, atomic::AtomicPtr<T>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ordering = load_ordering(self.context.atomic_ordering());
+        let ptr = self.atomic.load(ordering);
+
+        if self.context.is_json() {
+            json::fmt_json_string(f, format_args!("{:p}", ptr))
+        } else {
+            fmt::Pointer::fmt(&ptr, f)
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> ToDisplay for atomic::AtomicPtr<T> {
+    type Displayer<'a>
+        = DisplayAtomic<'a, Self>
+    where
+        T: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayAtomic {
+            atomic: self,
+            context,
+        }
+    }
+}
@@ -1,8 +1,13 @@
-use std::collections::BTreeMap;
-use std::fmt;
+use alloc::collections::BTreeMap;
+use core::fmt;
 
+use crate::compound::Elements;
+use crate::compound::Kind;
+use crate::json;
 use crate::Context;
 use crate::DisplayConfig;
+use crate::FieldVisitor;
+use crate::OutputStyle;
 use crate::ToDisplay;
 
 /// Displays a `BTreeMap<K, V>`.
@@ -25,27 +30,61 @@ where
     V: ToDisplay,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let max_items = self.context.max_items();
-
-        write!(f, "{{")?;
-        for (i, (k, v)) in self.map.iter().take(max_items).enumerate() {
-            if i > 0 {
-                write!(f, ", ")?;
-            }
-            write!(
-                f,
-                "{}: {}",
-                k.display_with_context(self.context),
-                v.display_with_context(self.context)
-            )?;
+        if self.context.output_style() == OutputStyle::Logfmt {
+            return crate::logfmt::fmt_logfmt(self.map, f, self.context);
         }
 
-        if self.map.len() > max_items {
-            write!(f, ", ...")?;
+        fmt_map(self.map, f, self.context)
+    }
+}
+
+/// Write at most `max_items` entries as `{k: v, ...}`, with the brackets and separators of the
+/// context, or as a JSON object with keys converted to strings.
+///
+/// Shared by the displayers of maps.
+pub(crate) fn fmt_map<'a, I, K, V>(
+    entries: I,
+    f: &mut fmt::Formatter<'_>,
+    context: Context,
+) -> fmt::Result
+where
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+    K: ToDisplay + ?Sized + 'a,
+    V: ToDisplay + ?Sized + 'a,
+{
+    let mut elements = Elements::begin(f, Kind::Map, context, None)?;
+    for (k, v) in entries {
+        if !elements.key(f, |key_context| k.display_with_context(key_context))? {
+            break;
         }
+        write!(f, "{}", v.display_with_context(context))?;
+    }
+    elements.end(f)
+}
 
-        write!(f, "}}")
+/// Visit at most `max_items` entries, each as a field named by its key in plain style, then a
+/// `...` field if some entries are left out.
+///
+/// Shared by the maps that are structured values.
+pub(crate) fn visit_map_fields<'a, I, K, V>(
+    entries: I,
+    visitor: &mut dyn FieldVisitor,
+    context: Context,
+) -> fmt::Result
+where
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+    K: ToDisplay + ?Sized + 'a,
+    V: ToDisplay + 'a,
+{
+    let key_context = json::plain(context.key_context());
+
+    for (i, (k, v)) in entries.into_iter().enumerate() {
+        if i == context.max_items() {
+            return visitor.visit(&"...", &"...");
+        }
+        visitor.visit(&k.display_with_context(key_context), v)?;
     }
+    Ok(())
 }
 
 impl<K, V> crate::ToDisplay for BTreeMap<K, V>
@@ -62,4 +101,14 @@ where
     fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
         DisplayBTreeMap { map: self, context }
     }
+
+    /// Visits at most `max_items` entries, each as a field named by its key, then a `...` field
+    /// if some entries are left out.
+    fn visit_fields(
+        &self,
+        visitor: &mut dyn FieldVisitor,
+        context: Context,
+    ) -> Option<fmt::Result> {
+        Some(visit_map_fields(self, visitor, context))
+    }
 }
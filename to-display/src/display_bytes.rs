@@ -0,0 +1,133 @@
+use core::fmt;
+
+use crate::display_fn;
+use crate::display_slice::fmt_list;
+use crate::json;
+use crate::ByteFormat;
+use crate::Context;
+use crate::ToDisplay;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl ToDisplay for u8 {
+    type Displayer<'a> = &'a Self;
+
+    fn display_with_context(&self, _context: Context) -> Self::Displayer<'_> {
+        self
+    }
+
+    fn fmt_slice(slice: &[Self], f: &mut fmt::Formatter<'_>, context: Context) -> fmt::Result {
+        match context.byte_format() {
+            ByteFormat::List => fmt_list(slice, f, context),
+            _ if context.is_json() => {
+                let text = display_fn(|f, _| fmt_bytes(slice, f, json::plain(context)));
+                json::fmt_json_string(f, text)
+            }
+            _ => fmt_bytes(slice, f, context),
+        }
+    }
+}
+
+/// Write at most `max_items` bytes in a [`ByteFormat`] other than [`ByteFormat::List`].
+fn fmt_bytes(slice: &[u8], f: &mut fmt::Formatter<'_>, context: Context) -> fmt::Result {
+    let max_items = context.max_items();
+    let bytes = &slice[..slice.len().min(max_items)];
+    let truncated = slice.len() > max_items;
+
+    match context.byte_format() {
+        ByteFormat::List => return fmt_list(slice, f, context),
+        ByteFormat::Hex => fmt_hex(f, bytes, false)?,
+        ByteFormat::UpperHex => fmt_hex(f, bytes, true)?,
+        ByteFormat::Escaped => fmt_escaped(f, bytes)?,
+        ByteFormat::Base64 => fmt_base64(f, bytes)?,
+        ByteFormat::HexDump => {
+            fmt_hexdump(f, bytes)?;
+            if truncated && !bytes.is_empty() {
+                writeln!(f)?;
+            }
+        }
+    }
+
+    if truncated {
+        write!(f, "...")?;
+    }
+    Ok(())
+}
+
+fn fmt_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8], upper: bool) -> fmt::Result {
+    for b in bytes {
+        if upper {
+            write!(f, "{:02X}", b)?;
+        } else {
+            write!(f, "{:02x}", b)?;
+        }
+    }
+    Ok(())
+}
+
+fn fmt_escaped(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    write!(f, "b\"")?;
+    for b in bytes {
+        match b {
+            b'"' => write!(f, "\\\"")?,
+            b'\\' => write!(f, "\\\\")?,
+            b'\n' => write!(f, "\\n")?,
+            b'\r' => write!(f, "\\r")?,
+            b'\t' => write!(f, "\\t")?,
+            0x20..=0x7e => write!(f, "{}", *b as char)?,
+            _ => write!(f, "\\x{:02x}", b)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+fn fmt_base64(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (n >> (18 - 6 * i)) & 0x3f;
+                write!(f, "{}", BASE64[index as usize] as char)?;
+            } else {
+                write!(f, "=")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn fmt_hexdump(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    for (line, chunk) in bytes.chunks(16).enumerate() {
+        if line > 0 {
+            writeln!(f)?;
+        }
+
+        write!(f, "{:08x} ", line * 16)?;
+
+        for i in 0..16 {
+            if i == 8 {
+                write!(f, " ")?;
+            }
+            match chunk.get(i) {
+                Some(b) => write!(f, " {:02x}", b)?,
+                None => write!(f, "   ")?,
+            }
+        }
+
+        write!(f, "  |")?;
+        for b in chunk {
+            let c = if b.is_ascii_graphic() || *b == b' ' {
+                *b as char
+            } else {
+                '.'
+            };
+            write!(f, "{}", c)?;
+        }
+        write!(f, "|")?;
+    }
+    Ok(())
+}
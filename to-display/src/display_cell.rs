@@ -0,0 +1,95 @@
+use core::cell::Cell;
+use core::cell::RefCell;
+use core::fmt;
+
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays a `Cell<T>` by copying out its value.
+///
+/// This is the return value of calling a `Cell::display()`.
+pub struct DisplayCell<'a, T> {
+    cell: &'a Cell<T>,
+    context: Context,
+}
+
+/// Displays a `RefCell<T>`.
+///
+/// This is the return value of calling a `RefCell::display()`.
+///
+/// It never panics: if the value is mutably borrowed, it displays `<locked>`.
+pub struct DisplayRefCell<'a, T: ?Sized> {
+    cell: &'a RefCell<T>,
+    context: Context,
+}
+
+impl<T> DisplayConfig for DisplayCell<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T: ?Sized> DisplayConfig for DisplayRefCell<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> fmt::Display for DisplayCell<'_, T>
+where
+    T: ToDisplay + Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.cell.get();
+        let displayer = value.display_with_context(self.context);
+        write!(f, "{}", displayer)
+    }
+}
+
+impl<T> fmt::Display for DisplayRefCell<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.cell.try_borrow() {
+            Ok(value) => write!(f, "{}", value.display_with_context(self.context)),
+            Err(_) => json::fmt_str(f, self.context, "<locked>"),
+        }
+    }
+}
+
+impl<T> ToDisplay for Cell<T>
+where
+    T: ToDisplay + Copy,
+{
+    type Displayer<'a>
+        = DisplayCell<'a, T>
+    where
+        T: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayCell {
+            cell: self,
+            context,
+        }
+    }
+}
+
+impl<T> ToDisplay for RefCell<T>
+where
+    T: ToDisplay + ?Sized,
+{
+    type Displayer<'a>
+        = DisplayRefCell<'a, T>
+    where
+        T: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayRefCell {
+            cell: self,
+            context,
+        }
+    }
+}
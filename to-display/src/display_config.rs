@@ -1,5 +1,18 @@
-use crate::context::LocalOrUTC;
+use core::sync::atomic::Ordering;
+
+#[cfg(feature = "_time")]
+use crate::clock::Clock;
+use crate::ByteFormat;
 use crate::Context;
+#[cfg(feature = "std-time")]
+use crate::InvalidTimeFormat;
+use crate::OutputStyle;
+use crate::RangeFormat;
+#[cfg(feature = "std-time")]
+use crate::StrftimeFormat;
+use crate::TimeFormat;
+use crate::Timezone;
+use crate::UtcOffset;
 
 /// A customizable display wrapper.
 ///
@@ -29,6 +42,57 @@ pub trait DisplayConfig: Sized {
         self
     }
 
+    /// Set the style of the whole output tree, applied to every nested value.
+    fn with_output_style(mut self, output_style: OutputStyle) -> Self {
+        self.context_mut().output_style = Some(output_style);
+        self
+    }
+
+    /// Display structured values, such as derived structs and maps, as logfmt:
+    /// `a=1 b.c="quoted value"`.
+    ///
+    /// # Example:
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use to_display::DisplayConfig;
+    /// use to_display::ToDisplay;
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// let m = BTreeMap::from([("a", "x y"), ("b", "z")]);
+    ///
+    /// assert_eq!(m.display().logfmt().to_string(), r#"a="x y" b=z"#);
+    /// # }
+    /// ```
+    fn logfmt(self) -> Self {
+        self.with_output_style(OutputStyle::Logfmt)
+    }
+
+    /// Display every value as JSON: slices as arrays, maps and derived structs as objects, `None`
+    /// as `null` and strings quoted and escaped.
+    ///
+    /// Brackets and separators set with [`with_separator()`](Self::with_separator) and similar
+    /// methods are ignored, so that the output stays valid JSON.
+    ///
+    /// # Example:
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use to_display::DisplayConfig;
+    /// use to_display::ToDisplay;
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// let m = BTreeMap::from([(1, vec![Some("a\"b"), None])]);
+    ///
+    /// assert_eq!(m.display().json().to_string(), r#"{"1":["a\"b",null]}"#);
+    /// # }
+    /// ```
+    fn json(self) -> Self {
+        self.with_output_style(OutputStyle::Json)
+    }
+
     /// Set the maximum number of items to display for collections.
     ///
     /// Applies to slices, vectors, maps, and other collection types.
@@ -38,37 +102,309 @@ pub trait DisplayConfig: Sized {
         self
     }
 
-    /// Configures timestamps to display in local time.
-    fn use_local_time(mut self) -> Self {
-        self.context_mut().local_or_utc = Some(LocalOrUTC::Local);
+    /// Set the separator between the items of lists and the entries of maps, `", "` by default.
+    ///
+    /// The separator also precedes the `...` of a truncated collection.
+    fn with_separator(mut self, separator: &'static str) -> Self {
+        self.context_mut().separator = Some(separator);
         self
     }
 
-    /// Configures timestamps to display in UTC.
-    fn use_utc_time(mut self) -> Self {
-        self.context_mut().local_or_utc = Some(LocalOrUTC::Utc);
+    /// Set the opening and closing brackets of lists, `"["` and `"]"` by default.
+    ///
+    /// Use `("", "")` for bracketless lists, such as `1, 2, 3`.
+    fn with_list_brackets(mut self, open: &'static str, close: &'static str) -> Self {
+        self.context_mut().list_brackets = Some((open, close));
         self
     }
 
-    /// Sets a concise time format (`%H:%M:%S%.6f`).
-    fn use_short_time(mut self) -> Self {
-        self.context_mut().time_format = Some("SIMPLE");
+    /// Set the opening and closing brackets of maps, `"{"` and `"}"` by default.
+    fn with_map_brackets(mut self, open: &'static str, close: &'static str) -> Self {
+        self.context_mut().map_brackets = Some((open, close));
+        self
+    }
+
+    /// Set the separator between the key and the value of map entries, `": "` by default.
+    ///
+    /// For example, a map is displayed in logfmt style as `k=v k2=v2` with:
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use to_display::DisplayConfig;
+    /// use to_display::ToDisplay;
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// let m = BTreeMap::from([("k", 1), ("k2", 2)]);
+    /// let d = m
+    ///     .display()
+    ///     .with_map_brackets("", "")
+    ///     .with_separator(" ")
+    ///     .with_key_value_separator("=");
+    ///
+    /// assert_eq!(d.to_string(), "k=1 k2=2");
+    /// # }
+    /// ```
+    fn with_key_value_separator(mut self, separator: &'static str) -> Self {
+        self.context_mut().key_value_separator = Some(separator);
+        self
+    }
+
+    /// Set the maximum depth of nested sequences, maps and structs of a value displayed with
+    /// `display_serde()` or `display_valuable()`.
+    ///
+    /// A sequence, map or struct below this depth is displayed as `...`.
+    fn limit_depth(mut self, max_depth: usize) -> Self {
+        self.context_mut().max_depth = Some(max_depth);
+        self
+    }
+
+    /// Display the value of the struct fields with these names as `<redacted>`, for a value
+    /// displayed with `display_serde()` or `display_valuable()`.
+    fn redact_fields(mut self, fields: &'static [&'static str]) -> Self {
+        self.context_mut().redacted_fields = Some(fields);
+        self
+    }
+
+    /// Display a `Result::Ok` as `v` instead of `Ok(v)`, unless verbose mode is enabled.
+    ///
+    /// `Err(e)` is always displayed with the `Err(...)` wrapper.
+    fn bare_ok(mut self) -> Self {
+        self.context_mut().bare_ok = Some(true);
+        self
+    }
+
+    /// Display the captured backtrace of an error, if there is one.
+    fn with_backtrace(mut self) -> Self {
+        self.context_mut().backtrace = Some(true);
+        self
+    }
+
+    /// Wrap paths and OS strings in double quotes, escaping `"`, `\` and control chars such as
+    /// `\n` inside.
+    fn quote_paths(mut self) -> Self {
+        self.context_mut().quote_paths = Some(true);
+        self
+    }
+
+    /// Escape invalid UTF-8 in paths and OS strings as `\xNN` instead of replacing it with
+    /// `U+FFFD`.
+    fn escape_non_utf8(mut self) -> Self {
+        self.context_mut().escape_non_utf8 = Some(true);
+        self
+    }
+
+    /// Set the maximum number of chars of a path.
+    ///
+    /// A longer path is shortened in the middle, keeping its first and last components:
+    /// `/very/…/file.rs`.
+    fn limit_path_len(mut self, max_path_len: usize) -> Self {
+        self.context_mut().max_path_len = Some(max_path_len);
+        self
+    }
+
+    /// Quote and escape strings, C strings and chars the same way as `Debug`: `"a\u{1b}"`,
+    /// `'\n'`.
+    ///
+    /// This keeps control characters in untrusted input from breaking single-line output.
+    fn escape_strings(mut self) -> Self {
+        self.context_mut().escape_strings = Some(true);
+        self
+    }
+
+    /// Set the maximum number of chars of strings.
+    ///
+    /// A longer string is truncated and followed by `...`, which is inside the quotes in JSON:
+    /// `"abc..."`.
+    fn limit_str_len(mut self, max_str_len: usize) -> Self {
+        self.context_mut().max_str_len = Some(max_str_len);
+        self
+    }
+
+    /// Sets the format of byte slices `[u8]` and `Vec<u8>`.
+    ///
+    /// At most `max_items` bytes are displayed, followed by `...` if there are more.
+    fn with_byte_format(mut self, byte_format: ByteFormat) -> Self {
+        self.context_mut().byte_format = Some(byte_format);
         self
     }
 
+    /// Displays bytes as lowercase hex (`616200`).
+    fn use_hex_bytes(self) -> Self {
+        self.with_byte_format(ByteFormat::Hex)
+    }
+
+    /// Displays bytes as uppercase hex (`ABCD`).
+    fn use_upper_hex_bytes(self) -> Self {
+        self.with_byte_format(ByteFormat::UpperHex)
+    }
+
+    /// Displays bytes as an escaped byte string (`b"ab\x00"`).
+    fn use_escaped_bytes(self) -> Self {
+        self.with_byte_format(ByteFormat::Escaped)
+    }
+
+    /// Displays bytes as base64 (`YWIA`).
+    fn use_base64_bytes(self) -> Self {
+        self.with_byte_format(ByteFormat::Base64)
+    }
+
+    /// Displays bytes as a multi-line hexdump with offsets.
+    fn use_hexdump_bytes(self) -> Self {
+        self.with_byte_format(ByteFormat::HexDump)
+    }
+
+    /// Sets the memory ordering used to load the value of an atomic, such as `AtomicU64`.
+    fn with_atomic_ordering(mut self, ordering: Ordering) -> Self {
+        self.context_mut().atomic_ordering = Some(ordering);
+        self
+    }
+
+    /// Sets the format of ranges, such as `Range<T>`, and pairs of bounds [`DisplayBounds`].
+    ///
+    /// [`DisplayBounds`]: crate::DisplayBounds
+    fn with_range_format(mut self, range_format: RangeFormat) -> Self {
+        self.context_mut().range_format = Some(range_format);
+        self
+    }
+
+    /// Displays ranges in interval notation (`[3, 7)`, `(-∞, 5]`).
+    fn use_interval_ranges(self) -> Self {
+        self.with_range_format(RangeFormat::Interval)
+    }
+
+    /// Displays ranges in Rust syntax (`3..7`, `..=5`).
+    fn use_rust_ranges(self) -> Self {
+        self.with_range_format(RangeFormat::Rust)
+    }
+
+    /// Configures timestamps to display in the given [`Timezone`].
+    ///
+    /// Accepts a [`Timezone`], the fixed offset type of an enabled time backend, such as
+    /// `chrono::FixedOffset`, or with the `chrono-tz` feature, a `chrono_tz::Tz`.
+    ///
+    /// Like the other time settings, it is available without a time backend, but then has no
+    /// effect.
+    fn with_timezone(mut self, timezone: impl Into<Timezone>) -> Self {
+        self.context_mut().timezone = Some(timezone.into());
+        self
+    }
+
+    /// Configures timestamps to display in local time.
+    fn use_local_time(self) -> Self {
+        self.with_timezone(Timezone::Local)
+    }
+
+    /// Configures timestamps to display in UTC.
+    fn use_utc_time(self) -> Self {
+        self.with_timezone(Timezone::Utc)
+    }
+
+    /// Configures timestamps to display with a fixed offset from UTC, such as `+08:00`.
+    fn use_fixed_offset(self, offset: UtcOffset) -> Self {
+        self.with_timezone(Timezone::Fixed(offset))
+    }
+
+    /// Configures timestamps to display in a named IANA timezone, such as `Asia/Shanghai`.
+    #[cfg(feature = "chrono-tz")]
+    fn use_named_timezone(self, tz: chrono_tz::Tz) -> Self {
+        self.with_timezone(Timezone::Named(tz))
+    }
+
+    /// Sets a concise time format (`%H:%M:%S%.6f`).
+    fn use_short_time(self) -> Self {
+        self.with_time_format_spec(TimeFormat::Simple)
+    }
+
     /// Sets a detailed time format (`%Y-%m-%dT%H:%M:%S%.6fZ%z`).
-    fn use_full_time(mut self) -> Self {
-        self.context_mut().time_format = Some("FULL");
+    fn use_full_time(self) -> Self {
+        self.with_time_format_spec(TimeFormat::Full)
+    }
+
+    /// Sets the RFC 3339 time format (`2024-12-28T23:59:59.123456+08:00`).
+    fn use_rfc3339_time(self) -> Self {
+        self.with_time_format_spec(TimeFormat::Rfc3339)
+    }
+
+    /// Sets the RFC 2822 time format (`Sat, 28 Dec 2024 23:59:59 +0800`).
+    fn use_rfc2822_time(self) -> Self {
+        self.with_time_format_spec(TimeFormat::Rfc2822)
+    }
+
+    /// Displays timestamps as seconds since the Unix epoch.
+    fn use_unix_seconds(self) -> Self {
+        self.with_time_format_spec(TimeFormat::UnixSeconds)
+    }
+
+    /// Displays timestamps as milliseconds since the Unix epoch.
+    fn use_unix_millis(self) -> Self {
+        self.with_time_format_spec(TimeFormat::UnixMillis)
+    }
+
+    /// Displays timestamps as microseconds since the Unix epoch.
+    fn use_unix_micros(self) -> Self {
+        self.with_time_format_spec(TimeFormat::UnixMicros)
+    }
+
+    /// Displays only the date of timestamps (`2024-12-28`).
+    fn use_date_only(self) -> Self {
+        self.with_time_format_spec(TimeFormat::DateOnly)
+    }
+
+    /// Sets the time format.
+    ///
+    /// Accepts a [`TimeFormat`] or, with the `std-time` feature, a custom `StrftimeFormat`.
+    fn with_time_format_spec(mut self, time_format: impl Into<TimeFormat>) -> Self {
+        self.context_mut().time_format = Some(time_format.into());
         self
     }
 
-    /// Sets a custom time format string.
+    /// Sets a time format string.
+    ///
+    /// `"SIMPLE"` and `"FULL"` select [`TimeFormat::Simple`] and [`TimeFormat::Full`]. Any other
+    /// string is a custom format in the syntax of the `chrono` crate, see
+    /// [chrono::format::strftime](https://docs.rs/chrono/latest/chrono/format/strftime/index.html).
+    ///
+    /// A custom format requires the `std-time` feature and is ignored without it. An invalid one
+    /// is displayed as `<invalid time format "...">`; use `try_with_time_format()` to get the
+    /// error up front.
+    fn with_time_format(self, time_format: &'static str) -> Self {
+        match time_format {
+            "SIMPLE" => self.use_short_time(),
+            "FULL" => self.use_full_time(),
+            #[cfg(feature = "std-time")]
+            custom => self.with_time_format_spec(StrftimeFormat::from_static(custom)),
+            #[cfg(not(feature = "std-time"))]
+            _ => self,
+        }
+    }
+
+    /// Parses and sets a custom time format string.
     ///
     /// Uses the format syntax from the `chrono` crate.
     /// See [chrono::format::strftime](https://docs.rs/chrono/latest/chrono/format/strftime/index.html)
     /// for the full specification.
-    fn with_time_format(mut self, time_format: &'static str) -> Self {
-        self.context_mut().time_format = Some(time_format);
+    ///
+    /// Returns an error if the format string is invalid.
+    ///
+    /// The parsed format is kept in memory until the process exits, see
+    /// [`StrftimeFormat::parse()`].
+    #[cfg(feature = "std-time")]
+    fn try_with_time_format(self, time_format: &str) -> Result<Self, InvalidTimeFormat> {
+        let time_format = StrftimeFormat::parse(time_format)?;
+        Ok(self.with_time_format_spec(time_format))
+    }
+
+    /// Sets the clock used to convert time values to wall-clock time.
+    ///
+    /// The clock must be `'static`, such as one in a `static` item. To install a [`FixedClock`]
+    /// built at runtime, e.g. in tests, use [`scoped_clock()`] instead.
+    ///
+    /// [`FixedClock`]: crate::FixedClock
+    /// [`scoped_clock()`]: crate::scoped_clock
+    #[cfg(feature = "_time")]
+    fn with_clock(mut self, clock: &'static dyn Clock) -> Self {
+        self.context_mut().clock = Some(clock);
         self
     }
 }
@@ -0,0 +1,137 @@
+use std::fmt;
+use std::time::SystemTime;
+
+use crate::time_backend::fmt_system_time;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::Timezone;
+
+/// Displays a date time value of one of the supported time crates.
+///
+/// This is the return value of calling `display()` on a `chrono::DateTime<Tz>`,
+/// `chrono::NaiveDateTime`, `time::OffsetDateTime`, `jiff::Timestamp` or `jiff::Zoned`.
+///
+/// The original timezone of the value is not kept: it is displayed in the timezone and with the
+/// time format of the [`Context`], the same way as [`DisplayInstant`](crate::DisplayInstant).
+///
+/// A `NaiveDateTime` has no timezone to convert from: it is always displayed at its own wall-clock
+/// time, as UTC, and only the time format of the [`Context`] applies.
+pub struct DisplayDateTime {
+    system_time: SystemTime,
+    /// Whether the value is a wall-clock time that must not be moved to another timezone.
+    naive: bool,
+    context: Context,
+}
+
+impl DisplayConfig for DisplayDateTime {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl fmt::Display for DisplayDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let context = if self.naive {
+            Context {
+                timezone: Some(Timezone::Utc),
+                ..self.context
+            }
+        } else {
+            self.context
+        };
+        fmt_system_time(f, self.system_time, context)
+    }
+}
+
+#[cfg(feature = "std-time")]
+mod impl_chrono {
+    use chrono::DateTime;
+    use chrono::NaiveDateTime;
+    use chrono::TimeZone;
+
+    use super::DisplayDateTime;
+    use crate::Context;
+    use crate::ToDisplay;
+
+    impl<Tz> ToDisplay for DateTime<Tz>
+    where
+        Tz: TimeZone,
+    {
+        type Displayer<'a>
+            = DisplayDateTime
+        where
+            Tz: 'a;
+
+        fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+            DisplayDateTime {
+                system_time: self.clone().into(),
+                naive: false,
+                context,
+            }
+        }
+    }
+
+    /// A `NaiveDateTime` is displayed at its own wall-clock time, whatever the timezone of the
+    /// context: moving it to another timezone could shift it to another day.
+    impl ToDisplay for NaiveDateTime {
+        type Displayer<'a> = DisplayDateTime;
+
+        fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+            DisplayDateTime {
+                system_time: self.and_utc().into(),
+                naive: true,
+                context,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "time-crate")]
+mod impl_time_crate {
+    use super::DisplayDateTime;
+    use crate::Context;
+    use crate::ToDisplay;
+
+    impl ToDisplay for time::OffsetDateTime {
+        type Displayer<'a> = DisplayDateTime;
+
+        fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+            DisplayDateTime {
+                system_time: (*self).into(),
+                naive: false,
+                context,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "jiff")]
+mod impl_jiff {
+    use super::DisplayDateTime;
+    use crate::Context;
+    use crate::ToDisplay;
+
+    impl ToDisplay for jiff::Timestamp {
+        type Displayer<'a> = DisplayDateTime;
+
+        fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+            DisplayDateTime {
+                system_time: (*self).into(),
+                naive: false,
+                context,
+            }
+        }
+    }
+
+    impl ToDisplay for jiff::Zoned {
+        type Displayer<'a> = DisplayDateTime;
+
+        fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+            DisplayDateTime {
+                system_time: self.into(),
+                naive: false,
+                context,
+            }
+        }
+    }
+}
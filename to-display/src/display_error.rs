@@ -0,0 +1,230 @@
+use core::error::Error;
+use core::fmt;
+
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+use crate::ToDisplay;
+
+/// Displays an error and, in verbose mode, its chain of causes.
+///
+/// This is the return value of calling a `dyn Error::display()`.
+///
+/// It outputs the error with its `Display` implementation, e.g. `"top"`. In verbose mode it
+/// follows [`Error::source()`] and outputs `"top: cause1: cause2"`, with at most `max_items`
+/// causes.
+///
+/// If a backtrace is attached with [`DisplayError::attach_backtrace()`] and backtraces are enabled
+/// with [`DisplayConfig::with_backtrace()`], it is output on the following lines.
+pub struct DisplayError<'a, E: ?Sized> {
+    error: &'a E,
+    backtrace: Option<&'a dyn fmt::Display>,
+    context: Context,
+}
+
+impl<'a, E: ?Sized> DisplayError<'a, E> {
+    /// Create a displayer for an error.
+    ///
+    /// This is useful to implement [`ToDisplay`](crate::ToDisplay) for a concrete error type, see
+    /// [`impl_to_display_error!`](crate::impl_to_display_error).
+    pub fn new(error: &'a E, context: Context) -> Self {
+        DisplayError {
+            error,
+            backtrace: None,
+            context,
+        }
+    }
+
+    /// Attach a captured backtrace of the error.
+    pub fn attach_backtrace(mut self, backtrace: &'a dyn fmt::Display) -> Self {
+        self.backtrace = Some(backtrace);
+        self
+    }
+}
+
+impl<E: ?Sized> DisplayConfig for DisplayError<'_, E> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<E> fmt::Display for DisplayError<'_, E>
+where
+    E: Error + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            let plain = DisplayError {
+                context: json::plain(self.context),
+                ..*self
+            };
+            return json::fmt_json_string(f, plain);
+        }
+
+        write!(f, "{}", self.error)?;
+
+        if self.context.verbose() {
+            self.fmt_sources(f)?;
+        }
+
+        if let Some(backtrace) = self.backtrace {
+            if self.context.backtrace() {
+                write!(f, "\nbacktrace:\n{}", backtrace)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<E> DisplayError<'_, E>
+where
+    E: Error + ?Sized,
+{
+    fn fmt_sources(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let max_items = self.context.max_items();
+
+        let mut source = self.error.source();
+        let mut n = 0;
+
+        while let Some(err) = source {
+            if n >= max_items {
+                return write!(f, ": ...");
+            }
+
+            write!(f, ": {}", err)?;
+            source = err.source();
+            n += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// Implement [`ToDisplay`] for error types with [`DisplayError`].
+///
+/// A `Result<T, E>` is displayable if `E` is [`ToDisplay`]. Use this macro to display your own
+/// error types, with their chain of causes in verbose mode:
+/// ```
+/// use std::error::Error;
+/// use std::fmt;
+///
+/// use to_display::impl_to_display_error;
+/// use to_display::DisplayConfig;
+/// use to_display::ToDisplay;
+///
+/// #[derive(Debug)]
+/// struct ConfigError(std::num::ParseIntError);
+///
+/// impl fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "invalid config")
+///     }
+/// }
+///
+/// impl Error for ConfigError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// impl_to_display_error!(ConfigError);
+///
+/// let r: Result<u8, ConfigError> = "x".parse().map_err(ConfigError);
+/// assert_eq!(r.display().to_string(), "Err(invalid config)");
+/// assert_eq!(
+///     r.display().verbose().to_string(),
+///     "Err(invalid config: invalid digit found in string)"
+/// );
+/// ```
+///
+/// A generic error type can implement [`ToDisplay`] the same way, by returning
+/// [`DisplayError::new()`].
+///
+/// [`ToDisplay`]: crate::ToDisplay
+/// [`DisplayError`]: crate::DisplayError
+/// [`DisplayError::new()`]: crate::DisplayError::new
+#[macro_export]
+macro_rules! impl_to_display_error {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl $crate::ToDisplay for $t {
+                type Displayer<'a>
+                    = $crate::DisplayError<'a, Self>
+                where
+                    Self: 'a;
+
+                fn display_with_context(&self, context: $crate::Context) -> Self::Displayer<'_> {
+                    $crate::DisplayError::new(self, context)
+                }
+            }
+        )*
+    }
+}
+
+impl_to_display_error!(
+    dyn Error + '_,
+    dyn Error + Send + '_,
+    dyn Error + Send + Sync + '_
+);
+
+// Errors of the standard library, so that a `Result` of a standard operation is displayable.
+impl_to_display_error!(
+    core::array::TryFromSliceError,
+    core::cell::BorrowError,
+    core::cell::BorrowMutError,
+    core::char::CharTryFromError,
+    core::char::ParseCharError,
+    core::fmt::Error,
+    core::net::AddrParseError,
+    core::num::ParseFloatError,
+    core::num::ParseIntError,
+    core::num::TryFromIntError,
+    core::str::ParseBoolError,
+    core::str::Utf8Error
+);
+
+#[cfg(feature = "alloc")]
+impl_to_display_error!(
+    alloc::collections::TryReserveError,
+    alloc::ffi::NulError,
+    alloc::string::FromUtf16Error,
+    alloc::string::FromUtf8Error
+);
+
+#[cfg(feature = "std")]
+impl_to_display_error!(
+    std::env::VarError,
+    std::io::Error,
+    std::time::SystemTimeError
+);
+
+#[cfg(feature = "anyhow")]
+impl ToDisplay for anyhow::Error {
+    type Displayer<'a> = DisplayError<'a, dyn Error + Send + Sync + 'static>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        use std::backtrace::BacktraceStatus;
+
+        let d = DisplayError::new(self.as_ref(), context);
+
+        let backtrace = self.backtrace();
+        if backtrace.status() == BacktraceStatus::Captured {
+            d.attach_backtrace(backtrace)
+        } else {
+            d
+        }
+    }
+}
+
+/// `eyre` does not expose the backtrace captured by its handler, so only the chain of causes is
+/// displayed.
+#[cfg(feature = "eyre")]
+impl ToDisplay for eyre::Report {
+    type Displayer<'a> = DisplayError<'a, dyn Error + Send + Sync + 'static>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayError::new(self.as_ref(), context)
+    }
+}
@@ -0,0 +1,79 @@
+use core::fmt;
+
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays with a closure that receives the `Formatter` and the [`Context`].
+///
+/// This is the return value of calling [`display_fn()`].
+///
+/// It is also [`ToDisplay`]: when nested in another displayer, such as an `Option` or a slice, the
+/// closure receives the context of the parent.
+pub struct DisplayFn<F> {
+    f: F,
+    context: Context,
+}
+
+/// Create a displayer from a closure, without declaring a displayer type.
+///
+/// The closure receives the `Formatter` and the [`Context`] set by [`DisplayConfig`] methods or
+/// inherited from a parent displayer.
+///
+/// # Example:
+/// ```
+/// use to_display::display_fn;
+/// use to_display::DisplayConfig;
+///
+/// let d = display_fn(|f, ctx| {
+///     if ctx.verbose() {
+///         write!(f, "Foo {{ id: 42 }}")
+///     } else {
+///         write!(f, "Foo(42)")
+///     }
+/// });
+///
+/// assert_eq!(d.to_string(), "Foo(42)");
+/// assert_eq!(d.verbose().to_string(), "Foo { id: 42 }");
+/// ```
+pub fn display_fn<F>(f: F) -> DisplayFn<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>, Context) -> fmt::Result,
+{
+    DisplayFn {
+        f,
+        context: Context::default(),
+    }
+}
+
+impl<F> DisplayConfig for DisplayFn<F> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<F> fmt::Display for DisplayFn<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>, Context) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.f)(f, self.context)
+    }
+}
+
+impl<F> ToDisplay for DisplayFn<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>, Context) -> fmt::Result,
+{
+    type Displayer<'a>
+        = DisplayFn<&'a F>
+    where
+        Self: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayFn {
+            f: &self.f,
+            context,
+        }
+    }
+}
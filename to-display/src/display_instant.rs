@@ -1,10 +1,10 @@
+use std::fmt;
 use std::time::Instant;
 use std::time::SystemTime;
 
-use chrono::DateTime;
-use chrono::Local;
-use chrono::Utc;
-
+use crate::clock;
+use crate::time_backend::fmt_system_time;
+use crate::Clock;
 use crate::Context;
 use crate::DisplayConfig;
 use crate::ToDisplay;
@@ -12,9 +12,12 @@ use crate::ToDisplay;
 /// Displays a `std::time::Instant`.
 ///
 /// This is the return value of calling a `Instant::display()`.
+///
+/// The instant is converted to wall-clock time with the [`Clock`] in the [`Context`], or the one
+/// installed with [`scoped_clock()`](crate::scoped_clock), when it is formatted.
 #[derive()]
 pub struct DisplayInstant {
-    system_time: SystemTime,
+    instant: Instant,
     context: Context,
 }
 
@@ -24,17 +27,10 @@ impl DisplayConfig for DisplayInstant {
     }
 }
 
-impl std::fmt::Display for DisplayInstant {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let tfmt = self.context.time_format();
-
-        if self.context.is_local_time() {
-            let datetime: DateTime<Local> = self.system_time.into();
-            write!(f, "{}", datetime.format(tfmt))
-        } else {
-            let datetime: DateTime<Utc> = self.system_time.into();
-            write!(f, "{}", datetime.format(tfmt))
-        }
+impl fmt::Display for DisplayInstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let t = clock::with_clock(self.context.clock(), |c| to_system_time(self.instant, c));
+        fmt_system_time(f, t, self.context)
     }
 }
 
@@ -43,7 +39,7 @@ impl ToDisplay for Instant {
 
     fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
         DisplayInstant {
-            system_time: to_system_time(*self),
+            instant: *self,
             context,
         }
     }
@@ -52,7 +48,6 @@ impl ToDisplay for Instant {
 #[cfg(feature = "tokio-time")]
 mod impl_tokio_time {
 
-    use super::to_system_time;
     use crate::Context;
     use crate::DisplayInstant;
     use crate::ToDisplay;
@@ -65,27 +60,22 @@ mod impl_tokio_time {
 
         fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
             DisplayInstant {
-                system_time: to_system_time(self.into_std()),
+                instant: self.into_std(),
                 context,
             }
         }
     }
 }
 
-pub(crate) fn to_system_time<T>(t: T) -> SystemTime
-where
-    T: Into<Instant>,
-{
-    let sys_now = SystemTime::now();
-    let now = Instant::now();
-
-    let tt: Instant = t.into();
+/// Convert an `Instant` to wall-clock time, using the current time of `clock` as the reference.
+pub(crate) fn to_system_time(t: Instant, clock: &dyn Clock) -> SystemTime {
+    let (now, sys_now) = clock.now();
 
-    if now >= tt {
-        let d = now - tt;
+    if now >= t {
+        let d = now - t;
         sys_now - d
     } else {
-        let d = tt - now;
+        let d = t - now;
         sys_now + d
     }
 }
@@ -0,0 +1,117 @@
+use core::cell::Cell;
+use core::fmt;
+
+use crate::display_slice::fmt_list;
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays the items of a cloneable iterator, without collecting them.
+///
+/// This is the return value of calling [`display_iter()`].
+///
+/// It is displayed the same way as a slice: `[value1, value2, ...]`. The iterator is cloned each
+/// time it is formatted.
+pub struct DisplayIter<I> {
+    iter: I,
+    context: Context,
+}
+
+/// Displays the items of an iterator that is consumed when it is formatted.
+///
+/// This is the return value of calling [`display_iter_once()`].
+///
+/// It is displayed the same way as a slice: `[value1, value2, ...]`. Since the iterator is
+/// consumed, it is displayed as `<consumed>` if it is formatted again.
+pub struct DisplayIterOnce<I> {
+    iter: Cell<Option<I>>,
+    context: Context,
+}
+
+/// Create a displayer for the items of an `IntoIterator + Clone`, such as
+/// `map.values().filter(...)`.
+///
+/// # Example:
+/// ```
+/// use to_display::display_iter;
+/// use to_display::DisplayConfig;
+///
+/// let v = [1, 2, 3, 4, 5];
+/// let evens = v.iter().filter(|x| *x % 2 == 0);
+///
+/// assert_eq!(display_iter(evens).to_string(), "[2, 4]");
+/// assert_eq!(
+///     display_iter(1..10).limit_items(3).to_string(),
+///     "[1, 2, 3, ...]"
+/// );
+/// ```
+pub fn display_iter<I>(iter: I) -> DisplayIter<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: ToDisplay,
+{
+    DisplayIter {
+        iter,
+        context: Context::default(),
+    }
+}
+
+/// Create a displayer for the items of an iterator that can not be cloned.
+///
+/// The iterator is consumed by the first formatting.
+///
+/// # Example:
+/// ```
+/// use to_display::display_iter_once;
+///
+/// let d = display_iter_once([1, 2].into_iter().map(|x| x * 10));
+///
+/// assert_eq!(d.to_string(), "[10, 20]");
+/// assert_eq!(d.to_string(), "<consumed>");
+/// ```
+pub fn display_iter_once<I>(iter: I) -> DisplayIterOnce<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: ToDisplay,
+{
+    DisplayIterOnce {
+        iter: Cell::new(Some(iter.into_iter())),
+        context: Context::default(),
+    }
+}
+
+impl<I> DisplayConfig for DisplayIter<I> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<I> DisplayConfig for DisplayIterOnce<I> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<I> fmt::Display for DisplayIter<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: ToDisplay,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_list(self.iter.clone(), f, self.context)
+    }
+}
+
+impl<I> fmt::Display for DisplayIterOnce<I>
+where
+    I: Iterator,
+    I::Item: ToDisplay,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.iter.take() {
+            Some(iter) => fmt_list(iter, f, self.context),
+            None => json::fmt_str(f, self.context, "<consumed>"),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use alloc::string::String;
+use core::fmt;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::display_btreemap::fmt_map;
+use crate::display_btreemap::visit_map_fields;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::FieldVisitor;
+use crate::OutputStyle;
+use crate::ToDisplay;
+
+/// Displays a `serde_json::Value`.
+///
+/// This is the return value of calling a `Value::display()`.
+///
+/// Arrays are displayed the same way as slices and objects the same way as maps, truncated to
+/// `max_items` items. Strings are truncated to `max_str_len` chars. With
+/// [`json()`](crate::DisplayConfig::json), the output is valid JSON.
+///
+/// # Example:
+/// ```
+/// use to_display::DisplayConfig;
+/// use to_display::ToDisplay;
+///
+/// let v = serde_json::json!({"ids": [1, 2, 3], "name": "abcdef"});
+///
+/// assert_eq!(
+///     v.display().limit_items(2).limit_str_len(3).to_string(),
+///     "{ids: [1, 2, ...], name: abc...}"
+/// );
+/// assert_eq!(
+///     v.display().limit_items(2).limit_str_len(3).json().to_string(),
+///     r#"{"ids":[1,2,"..."],"name":"abc..."}"#
+/// );
+/// ```
+pub struct DisplayJsonValue<'a> {
+    value: &'a Value,
+    context: Context,
+}
+
+/// Displays a `serde_json::Map`, the object of a `serde_json::Value`.
+///
+/// This is the return value of calling a `Map::display()`.
+pub struct DisplayJsonMap<'a> {
+    map: &'a Map<String, Value>,
+    context: Context,
+}
+
+impl DisplayConfig for DisplayJsonValue<'_> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl DisplayConfig for DisplayJsonMap<'_> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl fmt::Display for DisplayJsonValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let context = self.context;
+
+        match self.value {
+            Value::Null => write!(f, "null"),
+            Value::Bool(b) => write!(f, "{}", b.display_with_context(context)),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::String(s) => write!(f, "{}", s.display_with_context(context)),
+            Value::Array(a) => write!(f, "{}", a.display_with_context(context)),
+            Value::Object(m) => write!(f, "{}", m.display_with_context(context)),
+        }
+    }
+}
+
+impl fmt::Display for DisplayJsonMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.output_style() == OutputStyle::Logfmt {
+            return crate::logfmt::fmt_logfmt(self.map, f, self.context);
+        }
+
+        fmt_map(self.map, f, self.context)
+    }
+}
+
+impl ToDisplay for Value {
+    type Displayer<'a> = DisplayJsonValue<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayJsonValue {
+            value: self,
+            context,
+        }
+    }
+
+    /// An object is visited as a map; other values are not structured.
+    fn visit_fields(
+        &self,
+        visitor: &mut dyn FieldVisitor,
+        context: Context,
+    ) -> Option<fmt::Result> {
+        self.as_object()?.visit_fields(visitor, context)
+    }
+}
+
+impl ToDisplay for Map<String, Value> {
+    type Displayer<'a> = DisplayJsonMap<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayJsonMap { map: self, context }
+    }
+
+    /// Visits the entries as fields, the same way as a `BTreeMap`.
+    fn visit_fields(
+        &self,
+        visitor: &mut dyn FieldVisitor,
+        context: Context,
+    ) -> Option<fmt::Result> {
+        Some(visit_map_fields(self, visitor, context))
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::sync::TryLockError;
+
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays a `Mutex<T>`.
+///
+/// This is the return value of calling a `Mutex::display()`.
+///
+/// It never blocks: if the mutex is locked, it displays `<locked>`; if it is poisoned, it
+/// displays `<poisoned>`.
+pub struct DisplayMutex<'a, T: ?Sized> {
+    mutex: &'a Mutex<T>,
+    context: Context,
+}
+
+/// Displays a `RwLock<T>`.
+///
+/// This is the return value of calling a `RwLock::display()`.
+///
+/// It never blocks: if the lock is held by a writer, it displays `<locked>`; if it is poisoned,
+/// it displays `<poisoned>`.
+pub struct DisplayRwLock<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+    context: Context,
+}
+
+impl<T: ?Sized> DisplayConfig for DisplayMutex<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T: ?Sized> DisplayConfig for DisplayRwLock<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> fmt::Display for DisplayMutex<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mutex.try_lock() {
+            Ok(value) => write!(f, "{}", value.display_with_context(self.context)),
+            Err(TryLockError::WouldBlock) => json::fmt_str(f, self.context, "<locked>"),
+            Err(TryLockError::Poisoned(_)) => json::fmt_str(f, self.context, "<poisoned>"),
+        }
+    }
+}
+
+impl<T> fmt::Display for DisplayRwLock<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lock.try_read() {
+            Ok(value) => write!(f, "{}", value.display_with_context(self.context)),
+            Err(TryLockError::WouldBlock) => json::fmt_str(f, self.context, "<locked>"),
+            Err(TryLockError::Poisoned(_)) => json::fmt_str(f, self.context, "<poisoned>"),
+        }
+    }
+}
+
+impl<T> ToDisplay for Mutex<T>
+where
+    T: ToDisplay + ?Sized,
+{
+    type Displayer<'a>
+        = DisplayMutex<'a, T>
+    where
+        T: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayMutex {
+            mutex: self,
+            context,
+        }
+    }
+}
+
+impl<T> ToDisplay for RwLock<T>
+where
+    T: ToDisplay + ?Sized,
+{
+    type Displayer<'a>
+        = DisplayRwLock<'a, T>
+    where
+        T: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayRwLock {
+            lock: self,
+            context,
+        }
+    }
+}
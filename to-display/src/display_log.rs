@@ -0,0 +1,66 @@
+use core::fmt;
+
+use log::kv::ToValue;
+use log::kv::Value;
+
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays a value as a key-value of a `log` record.
+///
+/// This is the return value of calling [`display_kv()`].
+///
+/// It implements `log::kv::ToValue` by borrowing itself, so the value is only formatted, with
+/// its [`Context`], when the logger writes the record.
+pub struct DisplayKv<'a, T: ?Sized> {
+    value: &'a T,
+    context: Context,
+}
+
+/// Create a displayer to attach a value to a `log` record as a key-value, without formatting it
+/// in advance.
+///
+/// # Example:
+/// ```
+/// use to_display::display_kv;
+/// use to_display::DisplayConfig;
+///
+/// let ids = [1, 2, 3];
+/// log::info!(ids = display_kv(&ids[..]).limit_items(2); "request");
+///
+/// assert_eq!(display_kv(&ids[..]).limit_items(2).to_string(), "[1, 2, ...]");
+/// ```
+pub fn display_kv<T>(value: &T) -> DisplayKv<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    DisplayKv {
+        value,
+        context: Context::default(),
+    }
+}
+
+impl<T: ?Sized> DisplayConfig for DisplayKv<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> fmt::Display for DisplayKv<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value.display_with_context(self.context))
+    }
+}
+
+impl<T> ToValue for DisplayKv<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    fn to_value(&self) -> Value<'_> {
+        Value::from_display(self)
+    }
+}
@@ -1,8 +1,9 @@
-use std::fmt;
-use std::fmt::Formatter;
+use core::fmt;
+use core::fmt::Formatter;
 
 use crate::Context;
 use crate::DisplayConfig;
+use crate::FieldVisitor;
 use crate::ToDisplay;
 
 /// Displays a `Option<T>`.
@@ -24,6 +25,13 @@ where
     T: ToDisplay,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            return match self.option {
+                Some(t) => write!(f, "{}", t.display_with_context(self.context)),
+                None => write!(f, "null"),
+            };
+        }
+
         match self.option {
             Some(t) => {
                 let d = t.display_with_context(self.context);
@@ -59,4 +67,13 @@ where
             context,
         }
     }
+
+    /// A `Some` is visited as the inner value; a `None` is not structured.
+    fn visit_fields(
+        &self,
+        visitor: &mut dyn FieldVisitor,
+        context: Context,
+    ) -> Option<fmt::Result> {
+        self.as_ref()?.visit_fields(visitor, context)
+    }
 }
@@ -0,0 +1,258 @@
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::fmt;
+use std::path::is_separator;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays a `Path` or `PathBuf`.
+///
+/// This is the return value of calling a `Path::display()`.
+///
+/// `Path` has an inherent `display()` method that takes precedence, so call it as
+/// `ToDisplay::display(path)`.
+///
+/// Invalid UTF-8 is replaced with `U+FFFD`, or escaped as `\xNN` if
+/// [`escape_non_utf8`](crate::DisplayConfig::escape_non_utf8) is enabled. A path longer than
+/// [`limit_path_len`](crate::DisplayConfig::limit_path_len) is shortened in the middle, as in
+/// `/very/…/file.rs`.
+pub struct DisplayPath<'a> {
+    path: &'a Path,
+    context: Context,
+}
+
+/// Displays an `OsStr` or `OsString`.
+///
+/// This is the return value of calling a `OsStr::display()`.
+///
+/// Call it as `ToDisplay::display(os_str)` to avoid the unstable inherent `OsStr::display()`.
+///
+/// Invalid UTF-8 is replaced with `U+FFFD`, or escaped as `\xNN` if
+/// [`escape_non_utf8`](crate::DisplayConfig::escape_non_utf8) is enabled.
+pub struct DisplayOsStr<'a> {
+    os_str: &'a OsStr,
+    context: Context,
+}
+
+impl DisplayConfig for DisplayPath<'_> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl DisplayConfig for DisplayOsStr<'_> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl fmt::Display for DisplayPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            let lossy = DisplayPath {
+                path: self.path,
+                context: json::plain(Context {
+                    max_path_len: self.context.max_path_len,
+                    ..Context::default()
+                }),
+            };
+            return json::fmt_json_string(f, lossy);
+        }
+
+        let bytes = self.path.as_os_str().as_encoded_bytes();
+        let max_len = self.context.max_path_len();
+
+        let w = OsWriter::new(self.context);
+
+        w.open(f)?;
+        match shorten(bytes, max_len) {
+            Some((head, tail)) => {
+                w.write(f, head)?;
+                write!(f, "{}", ELLIPSIS)?;
+                w.write(f, tail)?;
+            }
+            None => w.write(f, bytes)?,
+        }
+        w.close(f)
+    }
+}
+
+impl fmt::Display for DisplayOsStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            let lossy = DisplayOsStr {
+                os_str: self.os_str,
+                context: json::plain(Context::default()),
+            };
+            return json::fmt_json_string(f, lossy);
+        }
+
+        let w = OsWriter::new(self.context);
+
+        w.open(f)?;
+        w.write(f, self.os_str.as_encoded_bytes())?;
+        w.close(f)
+    }
+}
+
+/// Writes the encoded bytes of an `OsStr`, with optional quoting and escaping.
+struct OsWriter {
+    quote: bool,
+    escape: bool,
+}
+
+impl OsWriter {
+    fn new(context: Context) -> Self {
+        OsWriter {
+            quote: context.quote_paths(),
+            escape: context.escape_non_utf8(),
+        }
+    }
+
+    fn open(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.quote {
+            write!(f, "\"")?;
+        }
+        Ok(())
+    }
+
+    fn close(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.open(f)
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+        for chunk in bytes.utf8_chunks() {
+            if self.quote {
+                for c in chunk.valid().chars() {
+                    match c {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        // A line break must not end the line of a log record in a quoted path.
+                        c if c.is_control() => write!(f, "{}", c.escape_debug())?,
+                        _ => write!(f, "{}", c)?,
+                    }
+                }
+            } else {
+                write!(f, "{}", chunk.valid())?;
+            }
+
+            if chunk.invalid().is_empty() {
+                continue;
+            }
+
+            if self.escape {
+                for b in chunk.invalid() {
+                    write!(f, "\\x{:02x}", b)?;
+                }
+            } else {
+                write!(f, "{}", char::REPLACEMENT_CHARACTER)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Number of chars of the lossy UTF-8 representation.
+fn char_len(bytes: &[u8]) -> usize {
+    bytes
+        .utf8_chunks()
+        .map(|c| c.valid().chars().count() + usize::from(!c.invalid().is_empty()))
+        .sum()
+}
+
+/// Replaces the middle components of a shortened path.
+const ELLIPSIS: &str = "/…/";
+
+/// Split a path that is too long into a head and a tail to display as `head/…/tail`.
+///
+/// The head is the first component, including the root if the path is absolute. The tail is as
+/// many trailing components as fit in `max_len`, and at least the last one.
+///
+/// Returns `None` if the path fits in `max_len` or can not be shortened.
+fn shorten(bytes: &[u8], max_len: usize) -> Option<(&[u8], &[u8])> {
+    if char_len(bytes) <= max_len {
+        return None;
+    }
+
+    let is_sep = |b: &u8| b.is_ascii() && is_separator(*b as char);
+
+    let leading = bytes.iter().take_while(|b| is_sep(b)).count();
+    let head_end = bytes[leading..]
+        .iter()
+        .position(is_sep)
+        .map_or(bytes.len(), |p| leading + p);
+
+    let head = &bytes[..head_end];
+
+    let budget = max_len.saturating_sub(char_len(head) + ELLIPSIS.chars().count());
+
+    let mut tail_start = bytes.len();
+    for (i, b) in bytes.iter().enumerate().skip(head_end).rev() {
+        if !is_sep(b) {
+            continue;
+        }
+
+        if tail_start != bytes.len() && char_len(&bytes[i + 1..]) > budget {
+            break;
+        }
+        tail_start = i + 1;
+    }
+
+    let tail = &bytes[tail_start..];
+
+    // Nothing would be omitted in the middle.
+    if tail.is_empty() || tail_start <= head_end + 1 {
+        return None;
+    }
+
+    Some((head, tail))
+}
+
+impl ToDisplay for Path {
+    type Displayer<'a> = DisplayPath<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayPath {
+            path: self,
+            context,
+        }
+    }
+}
+
+impl ToDisplay for PathBuf {
+    type Displayer<'a> = DisplayPath<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayPath {
+            path: self.as_path(),
+            context,
+        }
+    }
+}
+
+impl ToDisplay for OsStr {
+    type Displayer<'a> = DisplayOsStr<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayOsStr {
+            os_str: self,
+            context,
+        }
+    }
+}
+
+impl ToDisplay for OsString {
+    type Displayer<'a> = DisplayOsStr<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayOsStr {
+            os_str: self.as_os_str(),
+            context,
+        }
+    }
+}
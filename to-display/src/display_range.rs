@@ -0,0 +1,225 @@
+use core::fmt;
+use core::ops::Bound;
+use core::ops::Range;
+use core::ops::RangeFrom;
+use core::ops::RangeFull;
+use core::ops::RangeInclusive;
+use core::ops::RangeTo;
+use core::ops::RangeToInclusive;
+
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::RangeFormat;
+use crate::ToDisplay;
+
+/// Displays a range, such as `Range<T>`, or a pair of bounds [`DisplayBounds`].
+///
+/// This is the return value of calling a `Range::display()`.
+///
+/// It is displayed in interval notation, `[3, 7)`, or in Rust syntax, `3..7`, according to the
+/// [`RangeFormat`] in the [`Context`]. In JSON, it is a string of the same text.
+pub struct DisplayRange<'a, T> {
+    start: Bound<&'a T>,
+    end: Bound<&'a T>,
+    context: Context,
+}
+
+/// Displays a `Bound<T>`: `Included(3)`, `Excluded(3)` or `Unbounded`.
+///
+/// This is the return value of calling a `Bound::display()`.
+pub struct DisplayBound<'a, T> {
+    bound: Bound<&'a T>,
+    context: Context,
+}
+
+impl<T> DisplayConfig for DisplayRange<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> DisplayConfig for DisplayBound<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> fmt::Display for DisplayRange<'_, T>
+where
+    T: ToDisplay,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            let plain = DisplayRange {
+                context: json::plain(self.context),
+                ..*self
+            };
+            return json::fmt_json_string(f, plain);
+        }
+
+        let ctx = self.context;
+
+        match ctx.range_format() {
+            RangeFormat::Interval => {
+                match self.start {
+                    Bound::Included(v) => write!(f, "[{}", v.display_with_context(ctx))?,
+                    Bound::Excluded(v) => write!(f, "({}", v.display_with_context(ctx))?,
+                    Bound::Unbounded => write!(f, "(-∞")?,
+                }
+
+                write!(f, ", ")?;
+
+                match self.end {
+                    Bound::Included(v) => write!(f, "{}]", v.display_with_context(ctx)),
+                    Bound::Excluded(v) => write!(f, "{})", v.display_with_context(ctx)),
+                    Bound::Unbounded => write!(f, "+∞)"),
+                }
+            }
+            RangeFormat::Rust => {
+                match self.start {
+                    Bound::Included(v) => write!(f, "{}", v.display_with_context(ctx))?,
+                    Bound::Excluded(_) => {
+                        let start = DisplayBound {
+                            bound: self.start,
+                            context: ctx,
+                        };
+                        let end = DisplayBound {
+                            bound: self.end,
+                            context: ctx,
+                        };
+                        return write!(f, "({}, {})", start, end);
+                    }
+                    Bound::Unbounded => {}
+                }
+
+                match self.end {
+                    Bound::Included(v) => write!(f, "..={}", v.display_with_context(ctx)),
+                    Bound::Excluded(v) => write!(f, "..{}", v.display_with_context(ctx)),
+                    Bound::Unbounded => write!(f, ".."),
+                }
+            }
+        }
+    }
+}
+
+impl<T> fmt::Display for DisplayBound<'_, T>
+where
+    T: ToDisplay,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            let plain = DisplayBound {
+                bound: self.bound,
+                context: json::plain(self.context),
+            };
+            return json::fmt_json_string(f, plain);
+        }
+
+        match self.bound {
+            Bound::Included(v) => write!(f, "Included({})", v.display_with_context(self.context)),
+            Bound::Excluded(v) => write!(f, "Excluded({})", v.display_with_context(self.context)),
+            Bound::Unbounded => write!(f, "Unbounded"),
+        }
+    }
+}
+
+impl<T> ToDisplay for Bound<T>
+where
+    T: ToDisplay,
+{
+    type Displayer<'a>
+        = DisplayBound<'a, T>
+    where
+        T: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayBound {
+            bound: self.as_ref(),
+            context,
+        }
+    }
+}
+
+/// A pair of bounds, the start and the end, displayed as a range.
+///
+/// A tuple `(Bound<T>, Bound<T>)` is a range for [`RangeBounds`](core::ops::RangeBounds), but
+/// it is not [`ToDisplay`] itself, which leaves room for a generic impl for tuples. Wrap it in
+/// this type instead:
+/// ```
+/// use std::ops::Bound;
+///
+/// use to_display::DisplayBounds;
+/// use to_display::ToDisplay;
+///
+/// let b = DisplayBounds(Bound::Excluded(3), Bound::Included(7));
+/// assert_eq!(b.display().to_string(), "(3, 7]");
+///
+/// let b = DisplayBounds::from((Bound::Included(3), Bound::Unbounded));
+/// assert_eq!(b.display().to_string(), "[3, +∞)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DisplayBounds<T>(pub Bound<T>, pub Bound<T>);
+
+impl<T> From<(Bound<T>, Bound<T>)> for DisplayBounds<T> {
+    fn from((start, end): (Bound<T>, Bound<T>)) -> Self {
+        DisplayBounds(start, end)
+    }
+}
+
+impl<T> ToDisplay for DisplayBounds<T>
+where
+    T: ToDisplay,
+{
+    type Displayer<'a>
+        = DisplayRange<'a, T>
+    where
+        T: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayRange {
+            start: self.0.as_ref(),
+            end: self.1.as_ref(),
+            context,
+        }
+    }
+}
+
+macro_rules! impl_to_display_range {
+    ($($t:ident),*) => {
+        $(
+            impl<T> ToDisplay for $t<T>
+            where T: ToDisplay
+            {
+                type Displayer<'a>
+                    = DisplayRange<'a, T>
+                where T: 'a;
+
+                fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+                    use core::ops::RangeBounds;
+
+                    DisplayRange {
+                        start: self.start_bound(),
+                        end: self.end_bound(),
+                        context,
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_to_display_range!(Range, RangeInclusive, RangeFrom, RangeTo, RangeToInclusive);
+
+/// A `RangeFull` has no bound value; `u8` is an arbitrary type parameter that is never displayed.
+impl ToDisplay for RangeFull {
+    type Displayer<'a> = DisplayRange<'a, u8>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayRange {
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+            context,
+        }
+    }
+}
@@ -1,12 +1,20 @@
-use std::fmt;
+use core::fmt;
 
 use crate::Context;
 use crate::DisplayConfig;
+use crate::DisplayText;
 use crate::ToDisplay;
 
-/// Displays a `Result<T,E>` if T and E are `Display`.
+/// Displays a `Result<T,E>` if T and E are `ToDisplay`.
 ///
-/// It outputs `"Ok(...)"` or `"Err(...)"`.
+/// It outputs `"Ok(...)"` or `"Err(...)"`. If [`bare_ok`] is enabled and verbose mode is not,
+/// it outputs the `Ok` value without the `Ok(...)` wrapper. In JSON it outputs `{"Ok":...}` or
+/// `{"Err":...}`.
+///
+/// An error type that is only `fmt::Display`, such as an error of another crate, is displayed with
+/// [`display_result()`] instead.
+///
+/// [`bare_ok`]: crate::DisplayConfig::bare_ok
 pub struct DisplayResult<'a, T, E> {
     result: &'a Result<T, E>,
     context: Context,
@@ -21,24 +29,23 @@ impl<T, E> DisplayConfig for DisplayResult<'_, T, E> {
 impl<T, E> fmt::Display for DisplayResult<'_, T, E>
 where
     T: ToDisplay,
-    E: fmt::Display,
+    E: ToDisplay,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.result {
-            Ok(ok) => {
-                write!(f, "Ok({})", ok.display_with_context(self.context))
-            }
-            Err(err) => {
-                write!(f, "Err({})", err)
-            }
-        }
+        let context = self.context;
+        let result = self
+            .result
+            .as_ref()
+            .map(|ok| ok.display_with_context(context))
+            .map_err(|err| err.display_with_context(context));
+        fmt_result(result, f, context)
     }
 }
 
 impl<T, E> ToDisplay for Result<T, E>
 where
     T: ToDisplay,
-    E: fmt::Display,
+    E: ToDisplay,
 {
     type Displayer<'a>
         = DisplayResult<'a, T, E>
@@ -53,3 +60,121 @@ where
         }
     }
 }
+
+/// Displays a `Result<T, E>` whose error is `fmt::Display` but not [`ToDisplay`].
+///
+/// This is the return value of calling [`display_result()`].
+///
+/// It is displayed the same way as a [`DisplayResult`], with the error written by its `Display`
+/// implementation, as a JSON string in JSON.
+pub struct DisplayResultText<'a, T, E> {
+    result: &'a Result<T, E>,
+    context: Context,
+}
+
+/// Create a displayer for a `Result` whose error is `fmt::Display` but not [`ToDisplay`], such as
+/// the error type of another crate, for which [`impl_to_display_error!`] can not be used.
+///
+/// The chain of causes of an error can be displayed in verbose mode by converting it to a
+/// `&dyn Error`, which is [`ToDisplay`]: `result.as_ref().map_err(|e| e as &dyn Error)`.
+///
+/// # Example:
+/// ```
+/// use std::fmt;
+///
+/// use to_display::display_result;
+/// use to_display::DisplayConfig;
+///
+/// struct Timeout;
+///
+/// impl fmt::Display for Timeout {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "timed out")
+///     }
+/// }
+///
+/// let r: Result<u8, Timeout> = Err(Timeout);
+/// assert_eq!(display_result(&r).to_string(), "Err(timed out)");
+/// assert_eq!(
+///     display_result(&r).json().to_string(),
+///     r#"{"Err":"timed out"}"#
+/// );
+/// ```
+///
+/// [`impl_to_display_error!`]: crate::impl_to_display_error
+pub fn display_result<T, E>(result: &Result<T, E>) -> DisplayResultText<'_, T, E>
+where
+    T: ToDisplay,
+    E: fmt::Display,
+{
+    DisplayResultText {
+        result,
+        context: Context::default(),
+    }
+}
+
+impl<T, E> DisplayConfig for DisplayResultText<'_, T, E> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T, E> fmt::Display for DisplayResultText<'_, T, E>
+where
+    T: ToDisplay,
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let context = self.context;
+        let result = self
+            .result
+            .as_ref()
+            .map(|ok| ok.display_with_context(context))
+            .map_err(|err| DisplayText::new(err, context));
+        fmt_result(result, f, context)
+    }
+}
+
+impl<T, E> ToDisplay for DisplayResultText<'_, T, E>
+where
+    T: ToDisplay,
+    E: fmt::Display,
+{
+    type Displayer<'a>
+        = DisplayResultText<'a, T, E>
+    where
+        Self: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayResultText {
+            result: self.result,
+            context,
+        }
+    }
+}
+
+/// Write the displayers of the `Ok` value or of the error as `Ok(...)` or `Err(...)`, or as
+/// `{"Ok":...}` or `{"Err":...}` in JSON.
+fn fmt_result(
+    result: Result<impl fmt::Display, impl fmt::Display>,
+    f: &mut fmt::Formatter<'_>,
+    context: Context,
+) -> fmt::Result {
+    if context.is_json() {
+        return match result {
+            Ok(ok) => write!(f, "{{\"Ok\":{}}}", ok),
+            Err(err) => write!(f, "{{\"Err\":{}}}", err),
+        };
+    }
+
+    match result {
+        Ok(ok) => {
+            if context.bare_ok() && !context.verbose() {
+                write!(f, "{}", ok)
+            } else {
+                write!(f, "Ok({})", ok)
+            }
+        }
+        Err(err) => write!(f, "Err({})", err),
+    }
+}
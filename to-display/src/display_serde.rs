@@ -0,0 +1,608 @@
+use alloc::string::String;
+use alloc::string::ToString;
+use core::fmt;
+
+use serde::ser;
+use serde::Serialize;
+
+use crate::compound::Elements;
+use crate::compound::Kind;
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::DisplayText;
+use crate::ToDisplay;
+
+/// Displays a value that implements `serde::Serialize`.
+///
+/// This is the return value of calling [`display_serde()`].
+///
+/// The value is serialized directly into the `Formatter`, without buffering:
+/// - Structs are displayed as `Foo { a: 1, b: x }`, sequences as `[1, 2]`, maps as `{k: v}` and
+///   tuples as `(1, 2)`.
+/// - `Option`, `max_items`, brackets and separators follow the [`Context`], the same way as for a
+///   [`ToDisplay`] value.
+/// - Sequences, maps and structs deeper than [`Context::max_depth()`] are displayed as `...`.
+/// - The value of a struct field listed in [`Context::redacted_fields()`] is displayed as
+///   `<redacted>`.
+///
+/// In JSON, the output is the same as `serde_json`, except for truncation.
+///
+/// If serializing fails, the error is displayed as `<error: msg>` after the output written so far.
+pub struct DisplaySerde<'a, T: ?Sized> {
+    value: &'a T,
+    context: Context,
+}
+
+/// Create a displayer for a value that implements `serde::Serialize`.
+///
+/// # Example:
+/// ```
+/// use serde::Serialize;
+/// use to_display::display_serde;
+/// use to_display::DisplayConfig;
+///
+/// #[derive(Serialize)]
+/// struct Login {
+///     user: String,
+///     password: String,
+///     roles: Vec<&'static str>,
+/// }
+///
+/// let login = Login {
+///     user: "alice".to_string(),
+///     password: "secret".to_string(),
+///     roles: vec!["admin", "dev", "ops"],
+/// };
+///
+/// assert_eq!(
+///     display_serde(&login)
+///         .redact_fields(&["password"])
+///         .limit_items(2)
+///         .to_string(),
+///     "Login { user: alice, password: <redacted>, roles: [admin, dev, ...] }"
+/// );
+/// ```
+pub fn display_serde<T>(value: &T) -> DisplaySerde<'_, T>
+where
+    T: Serialize + ?Sized,
+{
+    DisplaySerde {
+        value,
+        context: Context::default(),
+    }
+}
+
+impl<T: ?Sized> DisplayConfig for DisplaySerde<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> fmt::Display for DisplaySerde<'_, T>
+where
+    T: Serialize + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut serializer = Serializer {
+            f,
+            context: self.context,
+            depth: 0,
+        };
+
+        match self.value.serialize(&mut serializer) {
+            Ok(()) => Ok(()),
+            Err(Error::Fmt) => Err(fmt::Error),
+            Err(Error::Custom(msg)) => {
+                if self.context.is_json() {
+                    json::fmt_json_string(f, format_args!("<error: {}>", msg))
+                } else {
+                    write!(f, "<error: {}>", msg)
+                }
+            }
+        }
+    }
+}
+
+impl<T> ToDisplay for DisplaySerde<'_, T>
+where
+    T: Serialize + ?Sized,
+{
+    type Displayer<'a>
+        = DisplaySerde<'a, T>
+    where
+        Self: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplaySerde {
+            value: self.value,
+            context,
+        }
+    }
+}
+
+/// The error of [`Serializer`]: either the `Formatter` or the `Serialize` impl failed.
+#[derive(Debug)]
+enum Error {
+    Fmt,
+    Custom(String),
+}
+
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Self {
+        Error::Fmt
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Fmt => write!(f, "an error occurred when formatting"),
+            Error::Custom(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl ser::StdError for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+/// A `serde::Serializer` that writes into a `Formatter`.
+struct Serializer<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    context: Context,
+    /// The number of sequences, maps and structs that enclose the value being serialized.
+    depth: usize,
+}
+
+impl<'a, 'f> Serializer<'a, 'f> {
+    fn display<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ToDisplay + ?Sized,
+    {
+        write!(self.f, "{}", value.display_with_context(self.context))?;
+        Ok(())
+    }
+
+    /// Write the name of an enum variant, or open the `{"name":value}` object of it in JSON.
+    fn variant_prefix(&mut self, variant: &str) -> Result<(), Error> {
+        if self.context.is_json() {
+            self.f.write_str("{")?;
+            json::fmt_json_string(self.f, variant)?;
+            self.f.write_str(":")?;
+        } else {
+            self.f.write_str(variant)?;
+        }
+        Ok(())
+    }
+
+    /// Start a sequence, map or struct, or write `...` if it is deeper than `max_depth`.
+    ///
+    /// `prefix` is the name of an enum variant, or of a struct in plain style.
+    fn begin(&mut self, kind: Kind, prefix: Option<&str>) -> Result<Compound<'_, 'a, 'f>, Error> {
+        let elements = if self.depth >= self.context.max_depth() {
+            json::fmt_str(self.f, self.context, "...")?;
+            None
+        } else {
+            self.depth += 1;
+            Some(Elements::begin(self.f, kind, self.context, prefix)?)
+        };
+
+        Ok(Compound {
+            ser: self,
+            elements,
+            skip_value: false,
+        })
+    }
+}
+
+/// Serializes the elements of a sequence, map or struct.
+struct Compound<'s, 'a, 'f> {
+    ser: &'s mut Serializer<'a, 'f>,
+    /// `None` if the elements are not written because it is deeper than `max_depth`.
+    elements: Option<Elements>,
+    /// Whether the value of the current map entry is not written.
+    skip_value: bool,
+}
+
+impl Compound<'_, '_, '_> {
+    fn element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let Some(elements) = &mut self.elements else {
+            return Ok(());
+        };
+        if elements.next(self.ser.f)? {
+            value.serialize(&mut *self.ser)?;
+        }
+        Ok(())
+    }
+
+    fn key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let Some(elements) = &mut self.elements else {
+            self.skip_value = true;
+            return Ok(());
+        };
+        let written = elements.key(self.ser.f, |context| DisplaySerde {
+            value: key,
+            context,
+        })?;
+        self.skip_value = !written;
+        Ok(())
+    }
+
+    fn value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        if !self.skip_value {
+            value.serialize(&mut *self.ser)?;
+        }
+        Ok(())
+    }
+
+    fn field<T>(&mut self, name: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let Some(elements) = &mut self.elements else {
+            return Ok(());
+        };
+        if elements.field(self.ser.f, name)? {
+            value.serialize(&mut *self.ser)?;
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        if let Some(elements) = self.elements {
+            elements.end(self.ser.f)?;
+            self.ser.depth -= 1;
+        }
+        Ok(())
+    }
+}
+
+impl<'s, 'a, 'f> ser::Serializer for &'s mut Serializer<'a, 'f> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Compound<'s, 'a, 'f>;
+    type SerializeTuple = Compound<'s, 'a, 'f>;
+    type SerializeTupleStruct = Compound<'s, 'a, 'f>;
+    type SerializeTupleVariant = Compound<'s, 'a, 'f>;
+    type SerializeMap = Compound<'s, 'a, 'f>;
+    type SerializeStruct = Compound<'s, 'a, 'f>;
+    type SerializeStructVariant = Compound<'s, 'a, 'f>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.display(&v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.display(v)
+    }
+
+    /// Bytes follow the byte format of the context.
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        self.display(v)
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        let s = if self.context.is_json() {
+            "null"
+        } else if self.context.verbose() {
+            "None"
+        } else {
+            "-"
+        };
+        self.f.write_str(s)?;
+        Ok(())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        if self.context.verbose() && !self.context.is_json() {
+            self.f.write_str("Some(")?;
+            value.serialize(&mut *self)?;
+            self.f.write_str(")")?;
+            Ok(())
+        } else {
+            value.serialize(self)
+        }
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        self.display(&())
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<(), Error> {
+        let s = if self.context.is_json() { "null" } else { name };
+        self.f.write_str(s)?;
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        json::fmt_str(self.f, self.context, variant)?;
+        Ok(())
+    }
+
+    /// A newtype struct is displayed as its inner value, the same as `serde_json` does.
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.variant_prefix(variant)?;
+        if self.context.is_json() {
+            value.serialize(&mut *self)?;
+            self.f.write_str("}")?;
+        } else {
+            self.f.write_str("(")?;
+            value.serialize(&mut *self)?;
+            self.f.write_str(")")?;
+        }
+        Ok(())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'s, 'a, 'f>, Error> {
+        self.begin(Kind::List, None)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Compound<'s, 'a, 'f>, Error> {
+        self.begin(Kind::Tuple, None)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'s, 'a, 'f>, Error> {
+        if self.context.is_json() {
+            self.begin(Kind::Tuple, None)
+        } else {
+            self.begin(Kind::Tuple, Some(name))
+        }
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'s, 'a, 'f>, Error> {
+        self.begin(Kind::Tuple, Some(variant))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'s, 'a, 'f>, Error> {
+        self.begin(Kind::Map, None)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'s, 'a, 'f>, Error> {
+        if self.context.is_json() {
+            self.begin(Kind::Struct, None)
+        } else {
+            self.begin(Kind::Struct, Some(name))
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'s, 'a, 'f>, Error> {
+        self.begin(Kind::Struct, Some(variant))
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<(), Error>
+    where
+        T: fmt::Display + ?Sized,
+    {
+        write!(self.f, "{}", DisplayText::new(value, self.context))?;
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_, '_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_, '_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_, '_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_, '_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_, '_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.key(key)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.value(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_, '_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, name: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.field(name, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_, '_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, name: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.field(name, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
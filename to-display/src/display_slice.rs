@@ -1,5 +1,9 @@
-use std::fmt;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
 
+use crate::compound::Elements;
+use crate::compound::Kind;
 use crate::Context;
 use crate::DisplayConfig;
 use crate::ToDisplay;
@@ -23,22 +27,27 @@ where
     T: ToDisplay,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let max_items = self.context.max_items();
-
-        write!(f, "[")?;
-        for (i, t) in self.slice.iter().take(max_items).enumerate() {
-            if i > 0 {
-                write!(f, ", ")?;
-            }
-            write!(f, "{}", t.display_with_context(self.context))?;
-        }
+        T::fmt_slice(self.slice, f, self.context)
+    }
+}
 
-        if self.slice.len() > max_items {
-            write!(f, ", ...")?;
+/// Write items as `[value1, value2, ...]`, at most `max_items` items, with the brackets and
+/// separator of the context, or as a JSON array.
+///
+/// Shared by the displayers of slices and iterators.
+pub(crate) fn fmt_list<I>(items: I, f: &mut fmt::Formatter<'_>, context: Context) -> fmt::Result
+where
+    I: IntoIterator,
+    I::Item: ToDisplay,
+{
+    let mut elements = Elements::begin(f, Kind::List, context, None)?;
+    for t in items {
+        if !elements.next(f)? {
+            break;
         }
-
-        write!(f, "]")
+        write!(f, "{}", t.display_with_context(context))?;
     }
+    elements.end(f)
 }
 
 impl<T> crate::ToDisplay for [T]
@@ -58,6 +67,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> crate::ToDisplay for Vec<T>
 where
     T: ToDisplay,
@@ -0,0 +1,201 @@
+#[cfg(feature = "alloc")]
+use alloc::ffi::CString;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::ffi::CStr;
+use core::fmt;
+
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays a string: `&str` or `String`.
+///
+/// This is the return value of calling a `str::display()`.
+///
+/// If [`escape_strings`](crate::DisplayConfig::escape_strings) is enabled, the string is quoted
+/// and escaped the same way as `Debug`: `"a\u{1b}\n"`.
+///
+/// A string longer than [`max_str_len`](crate::Context::max_str_len) chars is truncated and
+/// followed by `...`.
+pub struct DisplayStr<'a> {
+    s: &'a str,
+    context: Context,
+}
+
+/// Displays a `char`.
+///
+/// This is the return value of calling a `char::display()`.
+///
+/// If [`escape_strings`](crate::DisplayConfig::escape_strings) is enabled, the char is quoted and
+/// escaped the same way as `Debug`: `'\n'`.
+pub struct DisplayChar<'a> {
+    c: &'a char,
+    context: Context,
+}
+
+/// Displays a C string: `CStr` or `CString`.
+///
+/// This is the return value of calling a `CStr::display()`.
+///
+/// Invalid UTF-8 is replaced with `U+FFFD`. If
+/// [`escape_strings`](crate::DisplayConfig::escape_strings) is enabled, the string is quoted and
+/// escaped, and invalid UTF-8 is escaped as `\xNN`: `"a\n\xff"`.
+pub struct DisplayCStr<'a> {
+    s: &'a CStr,
+    context: Context,
+}
+
+impl DisplayConfig for DisplayStr<'_> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl DisplayConfig for DisplayChar<'_> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl DisplayConfig for DisplayCStr<'_> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl fmt::Display for DisplayStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(head) = truncate(self.s, self.context.max_str_len()) {
+            return if self.context.is_json() {
+                json::fmt_json_string(f, format_args!("{}...", head))
+            } else if self.context.escape_strings() {
+                write!(f, "{:?}...", head)
+            } else {
+                write!(f, "{}...", head)
+            };
+        }
+
+        if self.context.is_json() {
+            json::fmt_json_string(f, self.s)
+        } else if self.context.escape_strings() {
+            fmt::Debug::fmt(self.s, f)
+        } else {
+            fmt::Display::fmt(self.s, f)
+        }
+    }
+}
+
+/// Returns the first `max_len` chars of `s`, or `None` if it is not longer than that.
+fn truncate(s: &str, max_len: usize) -> Option<&str> {
+    // A string has at most as many chars as bytes.
+    if s.len() <= max_len {
+        return None;
+    }
+    s.char_indices().nth(max_len).map(|(i, _)| &s[..i])
+}
+
+impl fmt::Display for DisplayChar<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            json::fmt_json_string(f, self.c)
+        } else if self.context.escape_strings() {
+            fmt::Debug::fmt(self.c, f)
+        } else {
+            fmt::Display::fmt(self.c, f)
+        }
+    }
+}
+
+impl fmt::Display for DisplayCStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            let lossy = DisplayCStr {
+                s: self.s,
+                context: json::plain(Context::default()),
+            };
+            return json::fmt_json_string(f, lossy);
+        }
+
+        let escape = self.context.escape_strings();
+
+        if escape {
+            write!(f, "\"")?;
+        }
+
+        for chunk in self.s.to_bytes().utf8_chunks() {
+            if escape {
+                for c in chunk.valid().chars() {
+                    // A single quote does not need escaping in a double-quoted string.
+                    if c == '\'' {
+                        write!(f, "'")?;
+                    } else {
+                        write!(f, "{}", c.escape_debug())?;
+                    }
+                }
+                for b in chunk.invalid() {
+                    write!(f, "\\x{:02x}", b)?;
+                }
+            } else {
+                write!(f, "{}", chunk.valid())?;
+                if !chunk.invalid().is_empty() {
+                    write!(f, "{}", char::REPLACEMENT_CHARACTER)?;
+                }
+            }
+        }
+
+        if escape {
+            write!(f, "\"")?;
+        }
+        Ok(())
+    }
+}
+
+impl ToDisplay for str {
+    type Displayer<'a> = DisplayStr<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayStr { s: self, context }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToDisplay for String {
+    type Displayer<'a> = DisplayStr<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayStr {
+            s: self.as_str(),
+            context,
+        }
+    }
+}
+
+impl ToDisplay for char {
+    type Displayer<'a> = DisplayChar<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayChar { c: self, context }
+    }
+}
+
+impl ToDisplay for CStr {
+    type Displayer<'a> = DisplayCStr<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayCStr { s: self, context }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToDisplay for CString {
+    type Displayer<'a> = DisplayCStr<'a>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayCStr {
+            s: self.as_c_str(),
+            context,
+        }
+    }
+}
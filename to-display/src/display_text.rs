@@ -0,0 +1,120 @@
+use core::fmt;
+
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+
+/// Displays a value with its own `Display` implementation, such as an `IpAddr`.
+///
+/// This is the return value of calling `display()` on a type that derives `ToDisplay` without
+/// `#[to_display(fields)]`, and on std types whose `Display` output is text.
+///
+/// Formatting options such as width are passed through. In JSON, the output is a quoted and
+/// escaped string.
+pub struct DisplayText<'a, T: ?Sized> {
+    value: &'a T,
+    context: Context,
+}
+
+impl<'a, T: ?Sized> DisplayText<'a, T> {
+    /// Create a displayer of `value` with the given [`Context`].
+    pub fn new(value: &'a T, context: Context) -> Self {
+        DisplayText { value, context }
+    }
+}
+
+impl<T: ?Sized> DisplayConfig for DisplayText<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> fmt::Display for DisplayText<'_, T>
+where
+    T: fmt::Display + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            json::fmt_json_string(f, self.value)
+        } else {
+            fmt::Display::fmt(self.value, f)
+        }
+    }
+}
+
+/// Displays a value that carries no data, such as `()` or `PhantomData`.
+///
+/// This is the return value of calling a `()::display()`.
+///
+/// It is displayed as its name, such as `()`, or as `null` in JSON.
+pub struct DisplayUnit {
+    name: &'static str,
+    context: Context,
+}
+
+impl DisplayUnit {
+    pub(crate) fn new(name: &'static str, context: Context) -> Self {
+        DisplayUnit { name, context }
+    }
+}
+
+impl DisplayConfig for DisplayUnit {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl fmt::Display for DisplayUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_json() {
+            write!(f, "null")
+        } else {
+            write!(f, "{}", self.name)
+        }
+    }
+}
+
+/// Displays a floating point number: `f32` or `f64`.
+///
+/// This is the return value of calling a `f64::display()`.
+///
+/// In JSON, `NaN` and infinities, which JSON can not represent, are displayed as `null`.
+pub struct DisplayFloat<'a, T> {
+    value: &'a T,
+    context: Context,
+}
+
+impl<T> DisplayConfig for DisplayFloat<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+macro_rules! impl_to_display_float {
+    ($($t:ty),*) => {
+        $(
+            impl fmt::Display for DisplayFloat<'_, $t> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if self.context.is_json() && !self.value.is_finite() {
+                        write!(f, "null")
+                    } else {
+                        fmt::Display::fmt(self.value, f)
+                    }
+                }
+            }
+
+            impl crate::ToDisplay for $t {
+                type Displayer<'a> = DisplayFloat<'a, $t>;
+
+                fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+                    DisplayFloat {
+                        value: self,
+                        context,
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_to_display_float!(f32, f64);
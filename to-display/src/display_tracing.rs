@@ -0,0 +1,132 @@
+use core::fmt;
+
+use tracing_core::dispatcher;
+use tracing_core::Subscriber;
+use tracing_subscriber::layer::Layer;
+
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays a value as a `tracing` field, with the default [`Context`] of the subscriber.
+///
+/// This is the return value of calling [`display_field()`].
+///
+/// The default context is the one of the [`ContextLayer`] of the subscriber that is current when
+/// [`display_field()`] is called, such as at the callsite of `info!`. Options set with
+/// [`DisplayConfig`] methods override it.
+///
+/// It implements both `Display` and `Debug`, so it can be recorded with `%` or `?`.
+pub struct DisplayField<'a, T: ?Sized> {
+    value: &'a T,
+    context: Context,
+    default: Option<Context>,
+}
+
+/// Create a displayer to record a value as a `tracing` field, with the default [`Context`] set by
+/// the [`ContextLayer`] of the subscriber.
+///
+/// A displayer returned by `display()` can also be recorded with `%`, such as
+/// `info!(state = %x.display())`, but it does not use the default context of the subscriber.
+///
+/// # Example:
+/// ```
+/// use to_display::display_field;
+/// use to_display::ContextLayer;
+/// use to_display::DisplayConfig;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber = tracing_subscriber::registry().with(ContextLayer::new().limit_items(2));
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     let ids = vec![1, 2, 3];
+///     assert_eq!(display_field(&ids).to_string(), "[1, 2, ...]");
+///     assert_eq!(display_field(&ids).limit_items(1).to_string(), "[1, ...]");
+///
+///     tracing::info!(ids = %display_field(&ids), "request");
+/// });
+/// ```
+pub fn display_field<T>(value: &T) -> DisplayField<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    DisplayField {
+        value,
+        context: Context::default(),
+        default: ContextLayer::current(),
+    }
+}
+
+impl<T: ?Sized> DisplayConfig for DisplayField<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> fmt::Display for DisplayField<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The subscriber can not be looked up here: the value is formatted while the event is
+        // dispatched, when the current subscriber is hidden to prevent recursion.
+        let context = match self.default {
+            Some(default) => self.context.or(default),
+            None => self.context,
+        };
+        write!(f, "{}", self.value.display_with_context(context))
+    }
+}
+
+/// Formats the same as `Display`, so that the field can be recorded with `?`.
+impl<T> fmt::Debug for DisplayField<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// A `tracing_subscriber` layer that sets the default [`Context`] of the values recorded with
+/// [`display_field()`].
+///
+/// The context is configured with [`DisplayConfig`] methods, in one place for the whole
+/// subscriber:
+/// ```
+/// use to_display::ContextLayer;
+/// use to_display::DisplayConfig;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber =
+///     tracing_subscriber::registry().with(ContextLayer::new().limit_items(8).limit_str_len(256));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ContextLayer {
+    context: Context,
+}
+
+impl ContextLayer {
+    /// Create a layer with the default [`Context`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the context of the layer.
+    pub fn context(&self) -> Context {
+        self.context
+    }
+
+    /// Returns the context of the `ContextLayer` of the current subscriber, if it has one.
+    pub fn current() -> Option<Context> {
+        dispatcher::get_default(|d| d.downcast_ref::<ContextLayer>().map(|l| l.context))
+    }
+}
+
+impl DisplayConfig for ContextLayer {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<S> Layer<S> for ContextLayer where S: Subscriber {}
@@ -0,0 +1,334 @@
+use core::fmt;
+
+use valuable::Enumerable;
+use valuable::Fields;
+use valuable::Listable;
+use valuable::Mappable;
+use valuable::NamedValues;
+use valuable::Structable;
+use valuable::Tuplable;
+use valuable::Valuable;
+use valuable::Value;
+use valuable::Visit;
+
+use crate::compound::Elements;
+use crate::compound::Kind;
+use crate::json;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::ToDisplay;
+
+/// Displays a value that implements `valuable::Valuable`.
+///
+/// This is the return value of calling [`display_valuable()`].
+///
+/// The value is visited directly into the `Formatter`, the same way as by
+/// [`display_serde()`](crate::display_serde):
+/// - Structs are displayed as `Foo { a: 1, b: x }`, lists as `[1, 2]`, maps as `{k: v}` and tuples
+///   as `(1, 2)`.
+/// - `()` and `None`, which are both `Value::Unit`, are displayed as `-`, or `None` in verbose
+///   mode.
+/// - `max_items`, brackets and separators follow the [`Context`], the same way as for a
+///   [`ToDisplay`] value.
+/// - Lists, maps, structs and tuples deeper than [`Context::max_depth()`] are displayed as `...`.
+/// - The value of a struct field listed in [`Context::redacted_fields()`] is displayed as
+///   `<redacted>`.
+pub struct DisplayValuable<'a, T: ?Sized> {
+    value: &'a T,
+    context: Context,
+}
+
+/// Create a displayer for a value that implements `valuable::Valuable`.
+///
+/// # Example:
+/// ```
+/// use to_display::display_valuable;
+/// use to_display::DisplayConfig;
+/// use valuable::Valuable;
+///
+/// #[derive(Valuable)]
+/// struct Login {
+///     user: String,
+///     password: String,
+///     roles: Vec<&'static str>,
+/// }
+///
+/// let login = Login {
+///     user: "alice".to_string(),
+///     password: "secret".to_string(),
+///     roles: vec!["admin", "dev", "ops"],
+/// };
+///
+/// assert_eq!(
+///     display_valuable(&login)
+///         .redact_fields(&["password"])
+///         .limit_items(2)
+///         .to_string(),
+///     "Login { user: alice, password: <redacted>, roles: [admin, dev, ...] }"
+/// );
+/// ```
+pub fn display_valuable<T>(value: &T) -> DisplayValuable<'_, T>
+where
+    T: Valuable + ?Sized,
+{
+    DisplayValuable {
+        value,
+        context: Context::default(),
+    }
+}
+
+impl<T: ?Sized> DisplayConfig for DisplayValuable<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> fmt::Display for DisplayValuable<'_, T>
+where
+    T: Valuable + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = Writer {
+            f,
+            context: self.context,
+            depth: 0,
+        };
+        w.value(self.value.as_value())
+    }
+}
+
+impl<T> ToDisplay for DisplayValuable<'_, T>
+where
+    T: Valuable + ?Sized,
+{
+    type Displayer<'a>
+        = DisplayValuable<'a, T>
+    where
+        Self: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayValuable {
+            value: self.value,
+            context,
+        }
+    }
+}
+
+/// Displays a `valuable::Value`, such as a map key.
+struct DisplayValue<'a> {
+    value: Value<'a>,
+    context: Context,
+}
+
+impl fmt::Display for DisplayValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = Writer {
+            f,
+            context: self.context,
+            depth: 0,
+        };
+        w.value(self.value)
+    }
+}
+
+/// Writes a `valuable::Value` into a `Formatter`.
+struct Writer<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    context: Context,
+    /// The number of lists, maps, structs and tuples that enclose the value being written.
+    depth: usize,
+}
+
+impl Writer<'_, '_> {
+    fn display<T>(&mut self, value: &T) -> fmt::Result
+    where
+        T: ToDisplay + ?Sized,
+    {
+        write!(self.f, "{}", value.display_with_context(self.context))
+    }
+
+    fn value(&mut self, value: Value<'_>) -> fmt::Result {
+        match value {
+            Value::Bool(v) => self.display(&v),
+            Value::Char(v) => self.display(&v),
+            Value::F32(v) => self.display(&v),
+            Value::F64(v) => self.display(&v),
+            Value::I8(v) => self.display(&v),
+            Value::I16(v) => self.display(&v),
+            Value::I32(v) => self.display(&v),
+            Value::I64(v) => self.display(&v),
+            Value::I128(v) => self.display(&v),
+            Value::Isize(v) => self.display(&v),
+            Value::U8(v) => self.display(&v),
+            Value::U16(v) => self.display(&v),
+            Value::U32(v) => self.display(&v),
+            Value::U64(v) => self.display(&v),
+            Value::U128(v) => self.display(&v),
+            Value::Usize(v) => self.display(&v),
+            Value::String(v) => self.display(v),
+            #[cfg(feature = "std")]
+            Value::Path(v) => self.display(v),
+            #[cfg(feature = "std")]
+            Value::Error(v) => self.display(v),
+            Value::Listable(v) => self.listable(v),
+            Value::Mappable(v) => self.mappable(v),
+            Value::Structable(v) => self.structable(v),
+            Value::Enumerable(v) => self.enumerable(v),
+            Value::Tuplable(v) => self.tuplable(v),
+            Value::Unit => self.unit(),
+            _ => json::fmt_str(self.f, self.context, "<unknown>"),
+        }
+    }
+
+    /// Write a `()` or `None` the same way as a `None`.
+    fn unit(&mut self) -> fmt::Result {
+        if self.context.is_json() {
+            write!(self.f, "null")
+        } else if self.context.verbose() {
+            write!(self.f, "None")
+        } else {
+            write!(self.f, "-")
+        }
+    }
+
+    fn listable(&mut self, v: &dyn Listable) -> fmt::Result {
+        self.compound(Kind::List, None, |c| v.visit(c))
+    }
+
+    fn mappable(&mut self, v: &dyn Mappable) -> fmt::Result {
+        self.compound(Kind::Map, None, |c| v.visit(c))
+    }
+
+    fn structable(&mut self, v: &dyn Structable) -> fmt::Result {
+        let def = v.definition();
+        let name = def.name();
+        let json = self.context.is_json();
+
+        match (def.fields(), json) {
+            (Fields::Named(_), true) => self.compound(Kind::Struct, None, |c| v.visit(c)),
+            (Fields::Named(_), false) => self.compound(Kind::Struct, Some(name), |c| v.visit(c)),
+            (Fields::Unnamed(_), true) => self.compound(Kind::Tuple, None, |c| v.visit(c)),
+            (Fields::Unnamed(_), false) => self.compound(Kind::Tuple, Some(name), |c| v.visit(c)),
+        }
+    }
+
+    /// Write a variant as `Name`, `Name(1)` or `Name { a: 1 }`, or in JSON as `"Name"`,
+    /// `{"Name":[1]}` or `{"Name":{"a":1}}`.
+    fn enumerable(&mut self, v: &dyn Enumerable) -> fmt::Result {
+        let variant = v.variant();
+        let name = variant.name();
+
+        match variant.fields() {
+            Fields::Unnamed(0) => json::fmt_str(self.f, self.context, name),
+            Fields::Named(_) => self.compound(Kind::Struct, Some(name), |c| v.visit(c)),
+            Fields::Unnamed(_) => self.compound(Kind::Tuple, Some(name), |c| v.visit(c)),
+        }
+    }
+
+    fn tuplable(&mut self, v: &dyn Tuplable) -> fmt::Result {
+        if v.definition().is_unit() {
+            return self.unit();
+        }
+
+        self.compound(Kind::Tuple, None, |c| v.visit(c))
+    }
+
+    /// Write a list, map, struct or tuple, whose elements are visited by `visit`, or write `...`
+    /// if it is deeper than `max_depth`.
+    ///
+    /// `prefix` is the name of an enum variant, or of a struct in plain style.
+    fn compound(
+        &mut self,
+        kind: Kind,
+        prefix: Option<&str>,
+        visit: impl FnOnce(&mut Compound<'_, '_, '_>),
+    ) -> fmt::Result {
+        if self.depth >= self.context.max_depth() {
+            return json::fmt_str(self.f, self.context, "...");
+        }
+
+        let elements = Elements::begin(self.f, kind, self.context, prefix)?;
+
+        self.depth += 1;
+        let mut c = Compound {
+            w: self,
+            elements,
+            res: Ok(()),
+        };
+        visit(&mut c);
+        let Compound { elements, res, .. } = c;
+        self.depth -= 1;
+        res?;
+
+        elements.end(self.f)
+    }
+}
+
+/// Visits the elements of a list, map, struct or tuple.
+///
+/// `Visit` methods can not fail: the first error is kept in `res`, and the rest of the elements
+/// are skipped.
+struct Compound<'w, 'a, 'f> {
+    w: &'w mut Writer<'a, 'f>,
+    elements: Elements,
+    res: fmt::Result,
+}
+
+impl Compound<'_, '_, '_> {
+    fn element(&mut self, value: Value<'_>) -> fmt::Result {
+        if self.elements.next(self.w.f)? {
+            self.w.value(value)?;
+        }
+        Ok(())
+    }
+
+    fn entry(&mut self, key: Value<'_>, value: Value<'_>) -> fmt::Result {
+        let written = self.elements.key(self.w.f, |context| DisplayValue {
+            value: key,
+            context,
+        })?;
+        if written {
+            self.w.value(value)?;
+        }
+        Ok(())
+    }
+
+    fn field(&mut self, name: &str, value: Value<'_>) -> fmt::Result {
+        if self.elements.field(self.w.f, name)? {
+            self.w.value(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl Visit for Compound<'_, '_, '_> {
+    fn visit_value(&mut self, value: Value<'_>) {
+        if self.res.is_ok() {
+            self.res = self.element(value);
+        }
+    }
+
+    fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+        for (field, value) in named_values.iter() {
+            if self.res.is_err() {
+                return;
+            }
+            self.res = self.field(field.name(), *value);
+        }
+    }
+
+    fn visit_unnamed_fields(&mut self, values: &[Value<'_>]) {
+        for value in values {
+            if self.res.is_err() {
+                return;
+            }
+            self.res = self.element(*value);
+        }
+    }
+
+    fn visit_entry(&mut self, key: Value<'_>, value: Value<'_>) {
+        if self.res.is_ok() {
+            self.res = self.entry(key, value);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use core::fmt;
+
+use crate::compound::Elements;
+use crate::compound::Kind;
+use crate::Context;
+use crate::DisplayConfig;
+use crate::OutputStyle;
+use crate::ToDisplay;
+
+/// A value that is visited as a field of a structured value.
+///
+/// It is implemented for every [`ToDisplay`] type, and is the object safe form of it used by a
+/// [`FieldVisitor`].
+pub trait Field {
+    /// Write the value with the given [`Context`].
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>, context: Context) -> fmt::Result;
+
+    /// Visit the fields of the value, if it is structured.
+    ///
+    /// Returns `None` if the value is not structured. See [`ToDisplay::visit_fields()`].
+    fn fields(&self, visitor: &mut dyn FieldVisitor, context: Context) -> Option<fmt::Result>;
+}
+
+impl<T> Field for T
+where
+    T: ToDisplay + ?Sized,
+{
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>, context: Context) -> fmt::Result {
+        write!(f, "{}", self.display_with_context(context))
+    }
+
+    fn fields(&self, visitor: &mut dyn FieldVisitor, context: Context) -> Option<fmt::Result> {
+        self.visit_fields(visitor, context)
+    }
+}
+
+/// Receives the fields of a structured value, such as a struct or a map.
+///
+/// See [`ToDisplay::visit_fields()`].
+pub trait FieldVisitor {
+    /// Visit a field with its name and value.
+    fn visit(&mut self, name: &dyn fmt::Display, value: &dyn Field) -> fmt::Result;
+}
+
+/// Displays a struct by its fields.
+///
+/// This is the return value of calling `display()` on a struct that derives `ToDisplay` with
+/// `#[to_display(fields)]`.
+///
+/// It is displayed as `Foo { a: 1, b: x }`, as `a=1 b=x` in [`OutputStyle::Logfmt`], or as
+/// `{"a":1,"b":"x"}` in [`OutputStyle::Json`].
+pub struct DisplayStruct<'a, T: ?Sized> {
+    value: &'a T,
+    name: &'static str,
+    context: Context,
+}
+
+impl<'a, T> DisplayStruct<'a, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    /// Create a displayer for a struct named `name`, whose fields are visited with
+    /// [`ToDisplay::visit_fields()`].
+    pub fn new(value: &'a T, name: &'static str, context: Context) -> Self {
+        DisplayStruct {
+            value,
+            name,
+            context,
+        }
+    }
+}
+
+impl<T: ?Sized> DisplayConfig for DisplayStruct<'_, T> {
+    fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl<T> fmt::Display for DisplayStruct<'_, T>
+where
+    T: ToDisplay + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = match self.context.output_style() {
+            OutputStyle::Plain => Some(self.name),
+            OutputStyle::Logfmt => return crate::logfmt::fmt_logfmt(self.value, f, self.context),
+            OutputStyle::Json => None,
+        };
+
+        let elements = Elements::begin(f, Kind::Struct, self.context, prefix)?;
+
+        let mut w = StructFields {
+            f,
+            elements,
+            context: self.context,
+        };
+        let res = self.value.visit_fields(&mut w, self.context);
+        let StructFields { elements, .. } = w;
+
+        res.unwrap_or(Ok(()))?;
+        elements.end(f)
+    }
+}
+
+/// Writes fields as ` a: 1, b: x`, or as `"a":1,"b":"x"` in JSON.
+struct StructFields<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    elements: Elements,
+    context: Context,
+}
+
+impl FieldVisitor for StructFields<'_, '_> {
+    fn visit(&mut self, name: &dyn fmt::Display, value: &dyn Field) -> fmt::Result {
+        if self.elements.field_name(self.f, name)? {
+            value.fmt_value(self.f, self.context)?;
+        }
+        Ok(())
+    }
+}
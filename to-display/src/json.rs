@@ -0,0 +1,52 @@
+//! Helpers for [`OutputStyle::Json`](crate::OutputStyle::Json).
+
+use core::fmt;
+use core::fmt::Write;
+
+use crate::Context;
+use crate::OutputStyle;
+
+/// Returns the context with the plain output style, to render a value that is embedded in a JSON
+/// string.
+pub(crate) fn plain(context: Context) -> Context {
+    Context {
+        output_style: Some(OutputStyle::Plain),
+        ..context
+    }
+}
+
+/// Write a literal such as `<locked>`, as a JSON string in JSON.
+pub(crate) fn fmt_str(f: &mut fmt::Formatter<'_>, context: Context, s: &str) -> fmt::Result {
+    if context.is_json() {
+        fmt_json_string(f, s)
+    } else {
+        f.write_str(s)
+    }
+}
+
+/// Write a value as a quoted and escaped JSON string, without buffering it.
+pub(crate) fn fmt_json_string(f: &mut fmt::Formatter<'_>, value: impl fmt::Display) -> fmt::Result {
+    f.write_char('"')?;
+    write!(JsonStringWriter(f), "{}", value)?;
+    f.write_char('"')
+}
+
+/// Escapes the content of a JSON string.
+struct JsonStringWriter<'a, 'f>(&'a mut fmt::Formatter<'f>);
+
+impl Write for JsonStringWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '"' => self.0.write_str("\\\"")?,
+                '\\' => self.0.write_str("\\\\")?,
+                '\n' => self.0.write_str("\\n")?,
+                '\r' => self.0.write_str("\\r")?,
+                '\t' => self.0.write_str("\\t")?,
+                c if (c as u32) < 0x20 => write!(self.0, "\\u{:04x}", c as u32)?,
+                c => self.0.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,24 +1,152 @@
 #![doc = include_str!("lib_readme.md")]
+#![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub(crate) mod byte_format;
+#[cfg(feature = "_time")]
+pub(crate) mod clock;
+pub(crate) mod compound;
 pub(crate) mod context;
+pub(crate) mod display_atomic;
+#[cfg(feature = "alloc")]
 pub(crate) mod display_btreemap;
+pub(crate) mod display_bytes;
+pub(crate) mod display_cell;
 pub(crate) mod display_config;
-#[cfg(feature = "std-time")]
+#[cfg(feature = "_time")]
+pub(crate) mod display_datetime;
+pub(crate) mod display_error;
+pub(crate) mod display_fn;
+#[cfg(feature = "_time")]
 pub(crate) mod display_instant;
+pub(crate) mod display_iter;
+#[cfg(feature = "serde_json")]
+pub(crate) mod display_json_value;
+#[cfg(feature = "std")]
+pub(crate) mod display_lock;
+#[cfg(feature = "log")]
+pub(crate) mod display_log;
 pub(crate) mod display_option;
+#[cfg(feature = "std")]
+pub(crate) mod display_path;
+pub(crate) mod display_range;
 pub(crate) mod display_result;
+#[cfg(feature = "serde")]
+pub(crate) mod display_serde;
 pub(crate) mod display_slice;
+pub(crate) mod display_str;
+pub(crate) mod display_text;
+#[cfg(feature = "tracing")]
+pub(crate) mod display_tracing;
+#[cfg(feature = "valuable")]
+pub(crate) mod display_valuable;
+pub(crate) mod fields;
+pub(crate) mod json;
+pub(crate) mod logfmt;
+pub(crate) mod output_style;
+pub(crate) mod range_format;
+#[cfg(feature = "std-time")]
+pub(crate) mod strftime_format;
+#[cfg(feature = "_time")]
+pub(crate) mod time_backend;
+pub(crate) mod time_format;
+pub(crate) mod timezone;
 pub(crate) mod to_display;
 mod to_display_impls;
 
+pub use byte_format::ByteFormat;
+#[cfg(feature = "_time")]
+pub use clock::scoped_clock;
+#[cfg(feature = "_time")]
+pub use clock::Clock;
+#[cfg(feature = "_time")]
+pub use clock::ClockGuard;
+#[cfg(feature = "_time")]
+pub use clock::FixedClock;
+#[cfg(feature = "_time")]
+pub use clock::SystemClock;
 pub use context::Context;
 pub use display_config::DisplayConfig;
+pub use fields::DisplayStruct;
+pub use fields::Field;
+pub use fields::FieldVisitor;
+pub use output_style::OutputStyle;
+pub use range_format::RangeFormat;
+#[cfg(feature = "std-time")]
+pub use strftime_format::InvalidTimeFormat;
+#[cfg(feature = "std-time")]
+pub use strftime_format::StrftimeFormat;
+pub use time_format::TimeFormat;
+pub use timezone::InvalidUtcOffset;
+pub use timezone::Timezone;
+pub use timezone::UtcOffset;
 pub use to_display::ToDisplay;
 pub use to_display_derive::ToDisplay;
+/// Re-export of the `valuable` crate, used by `#[to_display(fields, valuable)]`.
+#[cfg(feature = "valuable")]
+pub use valuable;
 
+pub use self::display_atomic::DisplayAtomic;
+#[cfg(feature = "alloc")]
 pub use self::display_btreemap::DisplayBTreeMap;
-#[cfg(feature = "std-time")]
+pub use self::display_cell::DisplayCell;
+pub use self::display_cell::DisplayRefCell;
+#[cfg(feature = "_time")]
+pub use self::display_datetime::DisplayDateTime;
+pub use self::display_error::DisplayError;
+pub use self::display_fn::display_fn;
+pub use self::display_fn::DisplayFn;
+#[cfg(feature = "_time")]
 pub use self::display_instant::DisplayInstant;
+pub use self::display_iter::display_iter;
+pub use self::display_iter::display_iter_once;
+pub use self::display_iter::DisplayIter;
+pub use self::display_iter::DisplayIterOnce;
+#[cfg(feature = "serde_json")]
+pub use self::display_json_value::DisplayJsonMap;
+#[cfg(feature = "serde_json")]
+pub use self::display_json_value::DisplayJsonValue;
+#[cfg(feature = "std")]
+pub use self::display_lock::DisplayMutex;
+#[cfg(feature = "std")]
+pub use self::display_lock::DisplayRwLock;
+#[cfg(feature = "log")]
+pub use self::display_log::display_kv;
+#[cfg(feature = "log")]
+pub use self::display_log::DisplayKv;
 pub use self::display_option::DisplayOption;
+#[cfg(feature = "std")]
+pub use self::display_path::DisplayOsStr;
+#[cfg(feature = "std")]
+pub use self::display_path::DisplayPath;
+pub use self::display_range::DisplayBound;
+pub use self::display_range::DisplayBounds;
+pub use self::display_range::DisplayRange;
+pub use self::display_result::display_result;
 pub use self::display_result::DisplayResult;
+pub use self::display_result::DisplayResultText;
+#[cfg(feature = "serde")]
+pub use self::display_serde::display_serde;
+#[cfg(feature = "serde")]
+pub use self::display_serde::DisplaySerde;
 pub use self::display_slice::DisplaySlice;
+pub use self::display_str::DisplayCStr;
+pub use self::display_str::DisplayChar;
+pub use self::display_str::DisplayStr;
+pub use self::display_text::DisplayFloat;
+pub use self::display_text::DisplayText;
+pub use self::display_text::DisplayUnit;
+#[cfg(feature = "tracing")]
+pub use self::display_tracing::display_field;
+#[cfg(feature = "tracing")]
+pub use self::display_tracing::ContextLayer;
+#[cfg(feature = "tracing")]
+pub use self::display_tracing::DisplayField;
+#[cfg(feature = "valuable")]
+pub use self::display_valuable::display_valuable;
+#[cfg(feature = "valuable")]
+pub use self::display_valuable::DisplayValuable;
@@ -0,0 +1,190 @@
+//! Writes structured values as logfmt: `a=1 b.c="quoted value"`.
+
+use core::fmt;
+use core::fmt::Write;
+
+use crate::Context;
+use crate::Field;
+use crate::FieldVisitor;
+
+/// Write a value as logfmt if it is structured, otherwise in the plain style.
+pub(crate) fn fmt_logfmt(
+    value: &(impl Field + ?Sized),
+    f: &mut fmt::Formatter<'_>,
+    context: Context,
+) -> fmt::Result {
+    let mut first = true;
+    let mut w = Logfmt {
+        f,
+        first: &mut first,
+        path: None,
+        context,
+    };
+
+    match value.fields(&mut w, context) {
+        Some(res) => res,
+        None => value.fmt_value(f, context),
+    }
+}
+
+/// The dotted key of a nested field, such as `a.b.c`.
+struct KeyPath<'a> {
+    parent: Option<&'a KeyPath<'a>>,
+    name: &'a dyn fmt::Display,
+}
+
+impl fmt::Display for KeyPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(parent) = self.parent {
+            write!(f, "{}.", parent)?;
+        }
+        write!(KeyWriter(f), "{}", self.name)
+    }
+}
+
+/// Flattens visited fields into `key=value` pairs separated by a space.
+struct Logfmt<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    first: &'a mut bool,
+    path: Option<&'a KeyPath<'a>>,
+    context: Context,
+}
+
+impl FieldVisitor for Logfmt<'_, '_> {
+    fn visit(&mut self, name: &dyn fmt::Display, value: &dyn Field) -> fmt::Result {
+        let path = KeyPath {
+            parent: self.path,
+            name,
+        };
+
+        let mut nested = Logfmt {
+            f: self.f,
+            first: self.first,
+            path: Some(&path),
+            context: self.context,
+        };
+        if let Some(res) = value.fields(&mut nested, self.context) {
+            return res;
+        }
+
+        if !*self.first {
+            write!(self.f, " ")?;
+        }
+        *self.first = false;
+
+        write!(self.f, "{}=", path)?;
+
+        let value = FieldValue {
+            value,
+            context: self.context,
+        };
+
+        // Format the value only once: it may change between two formattings.
+        let mut w = ValueWriter {
+            f: self.f,
+            buf: [0; VALUE_BUF_LEN],
+            len: 0,
+            quoted: false,
+        };
+        write!(w, "{}", value)?;
+        w.finish()
+    }
+}
+
+struct FieldValue<'a> {
+    value: &'a dyn Field,
+    context: Context,
+}
+
+impl fmt::Display for FieldValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_value(f, self.context)
+    }
+}
+
+/// Whether a char can not appear in an unquoted key or value.
+fn is_special(c: char) -> bool {
+    c <= ' ' || c == '=' || c == '"' || c.is_control() || c == char::REPLACEMENT_CHARACTER
+}
+
+/// The length of the longest value that is written unquoted when it needs no quoting.
+const VALUE_BUF_LEN: usize = 256;
+
+/// Writes a value, quoted if it is empty or contains a special char.
+///
+/// The value is buffered until it is known whether it needs quoting. A value that does not fit
+/// in the buffer is always quoted, which is valid for any logfmt value.
+struct ValueWriter<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    buf: [u8; VALUE_BUF_LEN],
+    len: usize,
+    quoted: bool,
+}
+
+impl ValueWriter<'_, '_> {
+    fn finish(self) -> fmt::Result {
+        if self.quoted {
+            return self.f.write_char('"');
+        }
+
+        let value = core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default();
+        if value.is_empty() || value.chars().any(is_special) {
+            self.f.write_char('"')?;
+            QuotedWriter(self.f).write_str(value)?;
+            self.f.write_char('"')
+        } else {
+            self.f.write_str(value)
+        }
+    }
+}
+
+impl Write for ValueWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !self.quoted {
+            if let Some(buf) = self.buf.get_mut(self.len..self.len + s.len()) {
+                buf.copy_from_slice(s.as_bytes());
+                self.len += s.len();
+                return Ok(());
+            }
+
+            self.f.write_char('"')?;
+            self.quoted = true;
+            // The buffer only holds whole `str`s, thus it is valid UTF-8.
+            let buffered = core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default();
+            QuotedWriter(self.f).write_str(buffered)?;
+        }
+        QuotedWriter(self.f).write_str(s)
+    }
+}
+
+/// Escapes a value inside double quotes.
+struct QuotedWriter<'a, 'f>(&'a mut fmt::Formatter<'f>);
+
+impl Write for QuotedWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '"' => self.0.write_str("\\\"")?,
+                '\\' => self.0.write_str("\\\\")?,
+                '\n' => self.0.write_str("\\n")?,
+                '\r' => self.0.write_str("\\r")?,
+                '\t' => self.0.write_str("\\t")?,
+                c if c.is_control() => write!(self.0, "\\u{:04x}", c as u32)?,
+                c => self.0.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Replaces special chars in a key with `_`, since a key can not be quoted.
+struct KeyWriter<'a, 'f>(&'a mut fmt::Formatter<'f>);
+
+impl Write for KeyWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.0.write_char(if is_special(c) { '_' } else { c })?;
+        }
+        Ok(())
+    }
+}
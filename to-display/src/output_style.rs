@@ -0,0 +1,31 @@
+/// The style of the whole output tree, set once at the top and applied to every nested value.
+///
+/// Set with [`DisplayConfig::with_output_style()`] or one of its shortcuts.
+///
+/// # Example:
+/// ```
+/// use to_display::OutputStyle;
+///
+/// assert_eq!(OutputStyle::default(), OutputStyle::Plain);
+/// ```
+///
+/// [`DisplayConfig::with_output_style()`]: crate::DisplayConfig::with_output_style
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputStyle {
+    /// The human-readable style of each displayer, such as `[1, 2]` or `Foo { a: 1 }`.
+    #[default]
+    Plain,
+
+    /// logfmt: structured values, such as a struct deriving `ToDisplay` with
+    /// `#[to_display(fields)]` or a `BTreeMap`, are flattened into `key=value` pairs:
+    /// `a=1 b.c="quoted value"`.
+    ///
+    /// Values that are not structured are displayed in the plain style.
+    Logfmt,
+
+    /// JSON: slices are arrays, maps and derived structs are objects, `None` is `null`, and
+    /// strings, times and other textual values are quoted and escaped strings.
+    ///
+    /// A truncated collection ends with a `"..."` item, or a `"...": "..."` entry for an object.
+    Json,
+}
@@ -0,0 +1,24 @@
+/// How a range or a pair of bounds is formatted.
+///
+/// Set with [`DisplayConfig::with_range_format()`] or one of its shortcuts.
+///
+/// # Example:
+/// ```
+/// use to_display::RangeFormat;
+///
+/// assert_eq!(RangeFormat::default(), RangeFormat::Interval);
+/// ```
+///
+/// [`DisplayConfig::with_range_format()`]: crate::DisplayConfig::with_range_format
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeFormat {
+    /// Mathematical interval notation: `[3, 7)`, `[3, 7]`, `(-∞, 5]`, `[3, +∞)`.
+    #[default]
+    Interval,
+
+    /// Rust syntax: `3..7`, `3..=7`, `..=5`, `3..`.
+    ///
+    /// A pair of bounds with an excluded start has no range syntax, and is displayed as a tuple:
+    /// `(Excluded(3), Included(7))`.
+    Rust,
+}
@@ -0,0 +1,190 @@
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::string::String;
+use std::string::ToString;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+use chrono::format::Item;
+use chrono::format::ParseError;
+use chrono::format::StrftimeItems;
+use chrono::DateTime;
+use chrono::TimeZone;
+
+/// Parsed format strings, keyed by the source string.
+///
+/// A format string is parsed and leaked at most once, so that a [`StrftimeFormat`] can be `Copy`
+/// and formats loaded at runtime do not leak memory on every use. Entries are never removed, see
+/// [`StrftimeFormat::parse()`].
+static INTERNED: Mutex<BTreeMap<String, &'static Parsed>> = Mutex::new(BTreeMap::new());
+
+#[derive(Debug)]
+struct Parsed {
+    source: String,
+    items: Vec<Item<'static>>,
+}
+
+/// A validated, pre-parsed `strftime` time format.
+///
+/// Build one with [`StrftimeFormat::parse()`], which reports an invalid format string up front
+/// instead of producing garbage when a time is displayed. The handle is `Copy`, so it can be
+/// stored in a [`Context`](crate::Context). Parsing the same string again returns the same
+/// handle without allocating.
+///
+/// The format syntax is that of the `chrono` crate, see
+/// [chrono::format::strftime](https://docs.rs/chrono/latest/chrono/format/strftime/index.html).
+///
+/// # Example:
+/// ```
+/// use to_display::StrftimeFormat;
+///
+/// let fmt = StrftimeFormat::parse("%Y-%m-%d").unwrap();
+/// assert_eq!(fmt.as_str(), "%Y-%m-%d");
+///
+/// assert!(StrftimeFormat::parse("%Y-%Q").is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct StrftimeFormat {
+    repr: Repr,
+}
+
+#[derive(Clone, Copy)]
+enum Repr {
+    /// A built-in format, or a `'static` one that has been validated.
+    Static(&'static str),
+    Parsed(&'static Parsed),
+    /// A `'static` format that failed validation, displayed in place of the time.
+    Invalid(&'static str),
+}
+
+impl StrftimeFormat {
+    /// `%H:%M:%S%.6f`, e.g., `23:59:59.123456`.
+    pub const SIMPLE: Self = Self::new_static("%H:%M:%S%.6f");
+
+    /// `%Y-%m-%dT%H:%M:%S%.6fZ%z`, e.g., `2024-12-28T23:59:59.123456Z+0800`.
+    pub const FULL: Self = Self::new_static("%Y-%m-%dT%H:%M:%S%.6fZ%z");
+
+    const fn new_static(format: &'static str) -> Self {
+        Self {
+            repr: Repr::Static(format),
+        }
+    }
+
+    /// Validate a `'static` format string, without interning it.
+    ///
+    /// An invalid format is displayed as `<invalid time format "...">` instead of the time.
+    pub(crate) fn from_static(format: &'static str) -> Self {
+        let invalid = StrftimeItems::new(format).any(|item| item == Item::Error);
+        let repr = if invalid {
+            Repr::Invalid(format)
+        } else {
+            Repr::Static(format)
+        };
+        Self { repr }
+    }
+
+    /// Parse and validate a `strftime` format string.
+    ///
+    /// The parsed format is interned in a process-wide table, so that the handle can be `Copy`:
+    /// - Every distinct format string is kept in memory until the process exits. This is meant for
+    ///   a few formats loaded from configuration, not for strings built per value.
+    /// - Every call takes a process-wide lock to look up the table. Parse a format once when it is
+    ///   configured and reuse the handle, rather than parsing it every time a value is displayed.
+    pub fn parse(format: &str) -> Result<Self, InvalidTimeFormat> {
+        let mut interned = INTERNED.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(parsed) = interned.get(format) {
+            return Ok(Self {
+                repr: Repr::Parsed(parsed),
+            });
+        }
+
+        let items = StrftimeItems::new(format)
+            .parse_to_owned()
+            .map_err(|error| InvalidTimeFormat {
+                format: format.to_string(),
+                error,
+            })?;
+
+        let parsed: &'static Parsed = Box::leak(Box::new(Parsed {
+            source: format.to_string(),
+            items,
+        }));
+        interned.insert(format.to_string(), parsed);
+
+        Ok(Self {
+            repr: Repr::Parsed(parsed),
+        })
+    }
+
+    /// Returns the format string this format was built from.
+    pub fn as_str(&self) -> &'static str {
+        match self.repr {
+            Repr::Static(s) | Repr::Invalid(s) => s,
+            Repr::Parsed(parsed) => &parsed.source,
+        }
+    }
+
+    /// Write `datetime` formatted with this format.
+    pub(crate) fn write<Tz>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        datetime: &DateTime<Tz>,
+    ) -> fmt::Result
+    where
+        Tz: TimeZone,
+        Tz::Offset: fmt::Display,
+    {
+        match self.repr {
+            Repr::Static(s) => write!(f, "{}", datetime.format(s)),
+            Repr::Parsed(parsed) => {
+                write!(f, "{}", datetime.format_with_items(parsed.items.iter()))
+            }
+            Repr::Invalid(s) => write!(f, "<invalid time format {:?}>", s),
+        }
+    }
+}
+
+impl fmt::Debug for StrftimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StrftimeFormat")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl PartialEq for StrftimeFormat {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for StrftimeFormat {}
+
+/// Error returned when a time format string is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTimeFormat {
+    format: String,
+    error: ParseError,
+}
+
+impl InvalidTimeFormat {
+    /// Returns the format string that failed to parse.
+    pub fn format(&self) -> &str {
+        &self.format
+    }
+}
+
+impl fmt::Display for InvalidTimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid time format {:?}: {}", self.format, self.error)
+    }
+}
+
+impl Error for InvalidTimeFormat {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -0,0 +1,199 @@
+//! Formatting of time values, with calendar computations delegated to a time crate.
+//!
+//! The backend is chosen by cargo features, in order of preference: `std-time` (chrono), `jiff`,
+//! `time-crate`. Every backend provides:
+//!
+//! - `civil(t, offset) -> Option<Civil>`: the calendar fields of `t` at `offset` seconds east of
+//!   UTC, or `None` if `t` is out of the range of the time crate. This must not panic.
+//! - `local_offset(t) -> i32`: the offset of the system local timezone at `t`.
+
+use std::fmt;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::clock;
+use crate::Context;
+use crate::TimeFormat;
+use crate::Timezone;
+
+#[cfg(feature = "std-time")]
+mod with_chrono;
+#[cfg(all(feature = "jiff", not(feature = "std-time")))]
+mod with_jiff;
+#[cfg(all(
+    feature = "time-crate",
+    not(feature = "std-time"),
+    not(feature = "jiff")
+))]
+mod with_time;
+
+#[cfg(feature = "std-time")]
+use with_chrono as backend;
+#[cfg(all(feature = "jiff", not(feature = "std-time")))]
+use with_jiff as backend;
+#[cfg(all(
+    feature = "time-crate",
+    not(feature = "std-time"),
+    not(feature = "jiff")
+))]
+use with_time as backend;
+
+#[cfg(not(any(feature = "std-time", feature = "time-crate", feature = "jiff")))]
+compile_error!(
+    "feature `_time` requires one of the time backends: `std-time`, `time-crate` or `jiff`"
+);
+
+/// Calendar fields of a point in time at a fixed offset from UTC.
+pub(crate) struct Civil {
+    pub(crate) year: i32,
+    /// 1 to 12.
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+    /// Days since Monday, 0 to 6.
+    pub(crate) weekday: u8,
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) microsecond: u32,
+    /// Seconds east of UTC.
+    pub(crate) offset: i32,
+}
+
+impl Civil {
+    /// `2024-12-28`
+    fn write_date(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    /// `23:59:59`
+    fn write_hms(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+
+    /// `23:59:59.123456`
+    fn write_time(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_hms(f)?;
+        write!(f, ".{:06}", self.microsecond)
+    }
+
+    /// `+0800`, or `+08:00` if `colon` is true.
+    fn write_offset(&self, f: &mut fmt::Formatter<'_>, colon: bool) -> fmt::Result {
+        let sign = if self.offset < 0 { '-' } else { '+' };
+        let abs = self.offset.unsigned_abs();
+        let (h, m) = (abs / 3600, abs % 3600 / 60);
+
+        if colon {
+            write!(f, "{}{:02}:{:02}", sign, h, m)
+        } else {
+            write!(f, "{}{:02}{:02}", sign, h, m)
+        }
+    }
+
+    fn weekday_name(&self) -> &'static str {
+        const NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+        NAMES[self.weekday as usize % 7]
+    }
+
+    /// Write in a calendar format; formats that are not based on the calendar fields are written
+    /// the same way as [`TimeFormat::Simple`].
+    fn write(&self, f: &mut fmt::Formatter<'_>, format: TimeFormat) -> fmt::Result {
+        match format {
+            TimeFormat::Full => {
+                self.write_date(f)?;
+                f.write_str("T")?;
+                self.write_time(f)?;
+                f.write_str("Z")?;
+                self.write_offset(f, false)
+            }
+            TimeFormat::Rfc3339 => {
+                self.write_date(f)?;
+                f.write_str("T")?;
+                self.write_time(f)?;
+                if self.offset == 0 {
+                    f.write_str("Z")
+                } else {
+                    self.write_offset(f, true)
+                }
+            }
+            TimeFormat::Rfc2822 => {
+                write!(
+                    f,
+                    "{}, {:02} {} {:04} ",
+                    self.weekday_name(),
+                    self.day,
+                    self.month_name(),
+                    self.year
+                )?;
+                self.write_hms(f)?;
+                f.write_str(" ")?;
+                self.write_offset(f, false)
+            }
+            TimeFormat::DateOnly => self.write_date(f),
+            _ => self.write_time(f),
+        }
+    }
+
+    fn month_name(&self) -> &'static str {
+        const NAMES: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+        NAMES[(self.month as usize + 11) % 12]
+    }
+}
+
+/// Format a point in time in the timezone and with the time format specified by `context`.
+///
+/// All time types share this function so that they are displayed consistently.
+pub(crate) fn fmt_system_time(
+    f: &mut fmt::Formatter<'_>,
+    t: SystemTime,
+    context: Context,
+) -> fmt::Result {
+    // In JSON, a time is always an RFC 3339 string, which needs no escaping.
+    if context.is_json() {
+        let context = Context {
+            output_style: None,
+            time_format: Some(TimeFormat::Rfc3339),
+            ..context
+        };
+        f.write_str("\"")?;
+        fmt_system_time(f, t, context)?;
+        return f.write_str("\"");
+    }
+
+    match context.time_format() {
+        TimeFormat::UnixSeconds => write!(f, "{}", unix_nanos(t).div_euclid(1_000_000_000)),
+        TimeFormat::UnixMillis => write!(f, "{}", unix_nanos(t).div_euclid(1_000_000)),
+        TimeFormat::UnixMicros => write!(f, "{}", unix_nanos(t).div_euclid(1_000)),
+        #[cfg(feature = "std-time")]
+        TimeFormat::Custom(custom) => with_chrono::fmt_custom(f, t, custom, context),
+        format => match backend::civil(t, utc_offset(t, context)) {
+            Some(c) => c.write(f, format),
+            // The backend can not represent `t`, e.g., past year 9999 with `time` or `jiff`.
+            None => f.write_str("<out of range>"),
+        },
+    }
+}
+
+/// Returns the offset in seconds east of UTC of the timezone in `context`, at `t`.
+pub(crate) fn utc_offset(t: SystemTime, context: Context) -> i32 {
+    match context.timezone() {
+        Timezone::Local => clock::with_clock(context.clock(), |c| c.local_offset(t).seconds()),
+        Timezone::Utc => 0,
+        Timezone::Fixed(offset) => offset.seconds(),
+        #[cfg(feature = "chrono-tz")]
+        Timezone::Named(tz) => with_chrono::named_offset(t, tz),
+    }
+}
+
+/// Returns the offset of the system local timezone at `t`, in seconds east of UTC.
+pub(crate) fn local_offset(t: SystemTime) -> i32 {
+    backend::local_offset(t)
+}
+
+fn unix_nanos(t: SystemTime) -> i128 {
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_nanos() as i128,
+        Err(e) => -(e.duration().as_nanos() as i128),
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+use std::time::SystemTime;
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::FixedOffset;
+use chrono::Local;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono::Timelike;
+use chrono::Utc;
+
+use super::utc_offset;
+use super::Civil;
+use crate::Context;
+use crate::StrftimeFormat;
+use crate::Timezone;
+
+pub(crate) fn civil(t: SystemTime, offset: i32) -> Option<Civil> {
+    let offset = FixedOffset::east_opt(offset)?;
+    let datetime = DateTime::<Utc>::from(t).with_timezone(&offset);
+
+    Some(Civil {
+        year: datetime.year(),
+        month: datetime.month() as u8,
+        day: datetime.day() as u8,
+        weekday: datetime.weekday().num_days_from_monday() as u8,
+        hour: datetime.hour() as u8,
+        minute: datetime.minute() as u8,
+        second: datetime.second() as u8,
+        microsecond: datetime.nanosecond() / 1_000,
+        offset: offset.local_minus_utc(),
+    })
+}
+
+pub(crate) fn local_offset(t: SystemTime) -> i32 {
+    let utc = DateTime::<Utc>::from(t).naive_utc();
+    Local.offset_from_utc_datetime(&utc).fix().local_minus_utc()
+}
+
+#[cfg(feature = "chrono-tz")]
+pub(crate) fn named_offset(t: SystemTime, tz: chrono_tz::Tz) -> i32 {
+    let utc = DateTime::<Utc>::from(t).naive_utc();
+    tz.offset_from_utc_datetime(&utc).fix().local_minus_utc()
+}
+
+/// Format with a custom `strftime` format, which is only supported by chrono.
+pub(crate) fn fmt_custom(
+    f: &mut fmt::Formatter<'_>,
+    t: SystemTime,
+    format: StrftimeFormat,
+    context: Context,
+) -> fmt::Result {
+    let datetime = DateTime::<Utc>::from(t);
+
+    match context.timezone() {
+        Timezone::Utc => format.write(f, &datetime),
+        // Use the zone itself rather than its offset, so that `%Z` shows the abbreviation.
+        #[cfg(feature = "chrono-tz")]
+        Timezone::Named(tz) => format.write(f, &datetime.with_timezone(&tz)),
+        _ => {
+            let offset = FixedOffset::east_opt(utc_offset(t, context)).ok_or(fmt::Error)?;
+            format.write(f, &datetime.with_timezone(&offset))
+        }
+    }
+}
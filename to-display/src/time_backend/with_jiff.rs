@@ -0,0 +1,32 @@
+use std::time::SystemTime;
+
+use jiff::tz::Offset;
+use jiff::tz::TimeZone;
+use jiff::Timestamp;
+
+use super::Civil;
+
+pub(crate) fn civil(t: SystemTime, offset: i32) -> Option<Civil> {
+    let timestamp = Timestamp::try_from(t).ok()?;
+    let offset = Offset::from_seconds(offset).ok()?;
+    let datetime = offset.to_datetime(timestamp);
+
+    Some(Civil {
+        year: datetime.year() as i32,
+        month: datetime.month() as u8,
+        day: datetime.day() as u8,
+        weekday: datetime.weekday().to_monday_zero_offset() as u8,
+        hour: datetime.hour() as u8,
+        minute: datetime.minute() as u8,
+        second: datetime.second() as u8,
+        microsecond: datetime.subsec_nanosecond() as u32 / 1_000,
+        offset: offset.seconds(),
+    })
+}
+
+pub(crate) fn local_offset(t: SystemTime) -> i32 {
+    match Timestamp::try_from(t) {
+        Ok(timestamp) => TimeZone::system().to_offset(timestamp).seconds(),
+        Err(_) => 0,
+    }
+}
@@ -0,0 +1,43 @@
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use time::OffsetDateTime;
+use time::UtcOffset;
+
+use super::Civil;
+
+pub(crate) fn civil(t: SystemTime, offset: i32) -> Option<Civil> {
+    let offset = UtcOffset::from_whole_seconds(offset).ok()?;
+    let datetime = to_offset_date_time(t)?.checked_to_offset(offset)?;
+
+    Some(Civil {
+        year: datetime.year(),
+        month: datetime.month() as u8,
+        day: datetime.day(),
+        weekday: datetime.weekday().number_days_from_monday(),
+        hour: datetime.hour(),
+        minute: datetime.minute(),
+        second: datetime.second(),
+        microsecond: datetime.microsecond(),
+        offset: offset.whole_seconds(),
+    })
+}
+
+/// Falls back to UTC if the local offset can not be determined, which the `time` crate refuses
+/// to do in a multi-threaded process on some platforms.
+pub(crate) fn local_offset(t: SystemTime) -> i32 {
+    to_offset_date_time(t)
+        .and_then(|datetime| UtcOffset::local_offset_at(datetime).ok())
+        .map(|offset| offset.whole_seconds())
+        .unwrap_or(0)
+}
+
+/// Returns `None` if `t` is out of the range of `OffsetDateTime`, e.g., past year 9999, for
+/// which `OffsetDateTime::from()` panics.
+fn to_offset_date_time(t: SystemTime) -> Option<OffsetDateTime> {
+    let since_epoch = match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => time::Duration::try_from(d).ok()?,
+        Err(e) => -time::Duration::try_from(e.duration()).ok()?,
+    };
+    OffsetDateTime::UNIX_EPOCH.checked_add(since_epoch)
+}
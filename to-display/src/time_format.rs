@@ -0,0 +1,59 @@
+#[cfg(feature = "std-time")]
+use crate::StrftimeFormat;
+
+/// How a time value is formatted.
+///
+/// Set with [`DisplayConfig::with_time_format_spec()`] or one of its shortcuts.
+///
+/// # Example:
+/// ```
+/// use to_display::TimeFormat;
+///
+/// assert_eq!(TimeFormat::default(), TimeFormat::Simple);
+/// ```
+///
+/// The enum is non-exhaustive: the `std-time` feature adds the `Custom` variant.
+///
+/// [`DisplayConfig::with_time_format_spec()`]: crate::DisplayConfig::with_time_format_spec
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeFormat {
+    /// Time of day with microseconds: `23:59:59.123456`.
+    #[default]
+    Simple,
+
+    /// Date and time with an offset: `2024-12-28T23:59:59.123456Z+0800`.
+    ///
+    /// Kept for compatibility; prefer [`TimeFormat::Rfc3339`].
+    Full,
+
+    /// RFC 3339 with microseconds: `2024-12-28T23:59:59.123456+08:00`, or with a `Z` suffix for
+    /// UTC: `2024-12-28T15:59:59.123456Z`.
+    Rfc3339,
+
+    /// RFC 2822: `Sat, 28 Dec 2024 23:59:59 +0800`.
+    Rfc2822,
+
+    /// Seconds since the Unix epoch: `1735401599`.
+    UnixSeconds,
+
+    /// Milliseconds since the Unix epoch: `1735401599123`.
+    UnixMillis,
+
+    /// Microseconds since the Unix epoch: `1735401599123456`.
+    UnixMicros,
+
+    /// The date only: `2024-12-28`.
+    DateOnly,
+
+    /// A custom `strftime` format. Only supported by the chrono backend.
+    #[cfg(feature = "std-time")]
+    Custom(StrftimeFormat),
+}
+
+#[cfg(feature = "std-time")]
+impl From<StrftimeFormat> for TimeFormat {
+    fn from(format: StrftimeFormat) -> Self {
+        TimeFormat::Custom(format)
+    }
+}
@@ -0,0 +1,142 @@
+use core::fmt;
+
+/// The timezone in which time values are displayed.
+///
+/// Set with [`DisplayConfig::with_timezone()`] or one of its shortcuts.
+///
+/// [`DisplayConfig::with_timezone()`]: crate::DisplayConfig::with_timezone
+///
+/// The enum is non-exhaustive: the `chrono-tz` feature adds the `Named` variant.
+#[derive(Default, Clone, Debug, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Timezone {
+    /// The local timezone, as reported by the `Clock`.
+    #[default]
+    Local,
+
+    /// UTC.
+    Utc,
+
+    /// A fixed offset from UTC, such as `+08:00`.
+    Fixed(UtcOffset),
+
+    /// A named IANA timezone, such as `Asia/Shanghai`.
+    #[cfg(feature = "chrono-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl From<UtcOffset> for Timezone {
+    fn from(offset: UtcOffset) -> Self {
+        Timezone::Fixed(offset)
+    }
+}
+
+#[cfg(feature = "std-time")]
+impl From<chrono::FixedOffset> for Timezone {
+    fn from(offset: chrono::FixedOffset) -> Self {
+        Timezone::Fixed(offset.into())
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+impl From<chrono_tz::Tz> for Timezone {
+    fn from(tz: chrono_tz::Tz) -> Self {
+        Timezone::Named(tz)
+    }
+}
+
+/// A fixed offset from UTC, such as `+08:00`.
+///
+/// It is less than 24 hours in either direction, which is the range supported by every time
+/// backend.
+///
+/// # Example:
+/// ```
+/// use to_display::UtcOffset;
+///
+/// let offset = UtcOffset::from_seconds(8 * 3600).unwrap();
+/// assert_eq!(offset.seconds(), 28800);
+///
+/// assert!(UtcOffset::from_seconds(24 * 3600).is_err());
+/// ```
+#[derive(Default, Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct UtcOffset {
+    seconds: i32,
+}
+
+impl UtcOffset {
+    /// The offset of UTC itself.
+    pub const UTC: Self = Self { seconds: 0 };
+
+    /// The largest offset in seconds: `23:59:59`.
+    const MAX_SECONDS: i32 = 86_399;
+
+    /// Create an offset of `seconds` east of UTC, such as `28800` for `+08:00`.
+    ///
+    /// Returns an error if the offset is 24 hours or more in either direction.
+    pub const fn from_seconds(seconds: i32) -> Result<Self, InvalidUtcOffset> {
+        if seconds < -Self::MAX_SECONDS || seconds > Self::MAX_SECONDS {
+            return Err(InvalidUtcOffset { seconds });
+        }
+        Ok(Self { seconds })
+    }
+
+    /// Returns the offset in seconds east of UTC.
+    pub const fn seconds(&self) -> i32 {
+        self.seconds
+    }
+}
+
+#[cfg(feature = "std-time")]
+impl From<chrono::FixedOffset> for UtcOffset {
+    fn from(offset: chrono::FixedOffset) -> Self {
+        // A `FixedOffset` has the same range.
+        Self {
+            seconds: offset.local_minus_utc(),
+        }
+    }
+}
+
+#[cfg(feature = "time-crate")]
+impl TryFrom<time::UtcOffset> for UtcOffset {
+    type Error = InvalidUtcOffset;
+
+    fn try_from(offset: time::UtcOffset) -> Result<Self, Self::Error> {
+        Self::from_seconds(offset.whole_seconds())
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::tz::Offset> for UtcOffset {
+    type Error = InvalidUtcOffset;
+
+    fn try_from(offset: jiff::tz::Offset) -> Result<Self, Self::Error> {
+        Self::from_seconds(offset.seconds())
+    }
+}
+
+/// Error returned when an offset from UTC is out of range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidUtcOffset {
+    seconds: i32,
+}
+
+impl InvalidUtcOffset {
+    /// Returns the offset in seconds east of UTC that was rejected.
+    pub fn seconds(&self) -> i32 {
+        self.seconds
+    }
+}
+
+impl fmt::Display for InvalidUtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UTC offset of {} seconds is out of range, it must be less than 24 hours",
+            self.seconds
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidUtcOffset {}
@@ -1,6 +1,7 @@
-use std::fmt;
+use core::fmt;
 
 use crate::Context;
+use crate::FieldVisitor;
 
 /// Create a displayable instance for a type.
 ///
@@ -25,10 +26,13 @@ use crate::Context;
 /// It displays an Option as `Some(value)` or `None`, if `verbose` is enabled.
 /// and it displays an Option as `-` or `value`, if `verbose` is disabled.
 ///
-/// It displays a Result as `Ok(value)` or `Err(error)`, if `verbose` is enabled.
+/// It displays a Result as `Ok(value)` or `Err(error)`. If `bare_ok` is enabled and `verbose` is
+/// not, it displays an `Ok` as `value`. An error that is a `dyn Error` is displayed with its chain
+/// of causes if `verbose` is enabled: `Err(top: cause1: cause2)`.
 ///
 /// It displays a slice as `[value1, value2, ...]`, if the number of items is less than or equal to
-/// `max_items`.
+/// `max_items`. A byte slice can instead be displayed as hex, base64, an escaped byte string or a
+/// hexdump, see [`ByteFormat`](crate::ByteFormat).
 ///
 /// [`DisplayConfig`]: crate::DisplayConfig
 pub trait ToDisplay {
@@ -51,4 +55,34 @@ pub trait ToDisplay {
     ///
     /// [`DisplayConfig`]: crate::DisplayConfig
     fn display_with_context(&self, context: Context) -> Self::Displayer<'_>;
+
+    /// Visit the fields of a structured value, such as a struct or a map.
+    ///
+    /// Output styles that flatten structured values, such as [`OutputStyle::Logfmt`], use it
+    /// to reach nested fields: `a.b=1`. Returns `None` if the value is not structured, which is
+    /// the default.
+    ///
+    /// It is implemented by `#[derive(ToDisplay)]` with `#[to_display(fields)]`.
+    ///
+    /// [`OutputStyle::Logfmt`]: crate::OutputStyle::Logfmt
+    fn visit_fields(
+        &self,
+        visitor: &mut dyn FieldVisitor,
+        context: Context,
+    ) -> Option<fmt::Result> {
+        let _ = (visitor, context);
+        None
+    }
+
+    /// Write a slice of this type, used by the displayer of `[T]` and `Vec<T>`.
+    ///
+    /// The default writes `[value1, value2, ...]`. `u8` overrides it to honor the
+    /// [`ByteFormat`](crate::ByteFormat) in the [`Context`].
+    #[doc(hidden)]
+    fn fmt_slice(slice: &[Self], f: &mut fmt::Formatter<'_>, context: Context) -> fmt::Result
+    where
+        Self: Sized,
+    {
+        crate::display_slice::fmt_list(slice, f, context)
+    }
 }
@@ -1,7 +1,18 @@
-use std::net;
-use std::num;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::cmp;
+use core::cmp::Reverse;
+use core::fmt;
+use core::marker::PhantomData;
+use core::net;
+use core::num;
+use core::num::Saturating;
+use core::num::Wrapping;
 
 use crate::Context;
+use crate::DisplayText;
+use crate::DisplayUnit;
+use crate::FieldVisitor;
 use crate::ToDisplay;
 
 macro_rules! impl_to_display_primitive {
@@ -27,28 +38,13 @@ impl_to_display_primitive!(
     i128,
     isize,
     // Unsigned integers
-    u8,
     u16,
     u32,
     u64,
     u128,
     usize,
-    // Floating point
-    f32,
-    f64,
     // Other primitives
     bool,
-    char,
-    // String types
-    String,
-    &str,
-    // Network types
-    net::IpAddr,
-    net::Ipv4Addr,
-    net::Ipv6Addr,
-    net::SocketAddr,
-    net::SocketAddrV4,
-    net::SocketAddrV6,
     // Numeric types
     num::NonZeroI8,
     num::NonZeroI16,
@@ -63,3 +59,142 @@ impl_to_display_primitive!(
     num::NonZeroU128,
     num::NonZeroUsize
 );
+
+/// A reference `&T` is displayed the same way as `T`.
+impl<T> ToDisplay for &T
+where
+    T: ToDisplay + ?Sized,
+{
+    type Displayer<'a>
+        = T::Displayer<'a>
+    where
+        Self: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        (**self).display_with_context(context)
+    }
+
+    fn visit_fields(
+        &self,
+        visitor: &mut dyn FieldVisitor,
+        context: Context,
+    ) -> Option<fmt::Result> {
+        (**self).visit_fields(visitor, context)
+    }
+}
+
+/// A `Box<T>` is displayed the same way as `T`.
+#[cfg(feature = "alloc")]
+impl<T> ToDisplay for Box<T>
+where
+    T: ToDisplay + ?Sized,
+{
+    type Displayer<'a>
+        = T::Displayer<'a>
+    where
+        Self: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        (**self).display_with_context(context)
+    }
+
+    fn visit_fields(
+        &self,
+        visitor: &mut dyn FieldVisitor,
+        context: Context,
+    ) -> Option<fmt::Result> {
+        (**self).visit_fields(visitor, context)
+    }
+}
+
+macro_rules! impl_to_display_text {
+    ($($t:ty),*) => {
+        $(
+            impl ToDisplay for $t {
+                type Displayer<'a> = DisplayText<'a, Self>;
+
+                fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+                    DisplayText::new(self, context)
+                }
+            }
+        )*
+    }
+}
+
+impl_to_display_text!(
+    // Network types
+    net::IpAddr,
+    net::Ipv4Addr,
+    net::Ipv6Addr,
+    net::SocketAddr,
+    net::SocketAddrV4,
+    net::SocketAddrV6
+);
+
+macro_rules! impl_to_display_forward {
+    ($($t:ident),*) => {
+        $(
+            /// Displayed the same way as the inner value.
+            impl<T> ToDisplay for $t<T>
+            where T: ToDisplay
+            {
+                type Displayer<'a>
+                    = T::Displayer<'a>
+                where Self: 'a;
+
+                fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+                    self.0.display_with_context(context)
+                }
+            }
+        )*
+    }
+}
+
+impl_to_display_forward!(Wrapping, Saturating, Reverse);
+
+/// Displayed as `Less`, `Equal` or `Greater`.
+impl ToDisplay for cmp::Ordering {
+    type Displayer<'a> = DisplayText<'static, str>;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        let name = match self {
+            cmp::Ordering::Less => "Less",
+            cmp::Ordering::Equal => "Equal",
+            cmp::Ordering::Greater => "Greater",
+        };
+        DisplayText::new(name, context)
+    }
+}
+
+/// Displayed as `()`, or `null` in JSON.
+impl ToDisplay for () {
+    type Displayer<'a> = DisplayUnit;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayUnit::new("()", context)
+    }
+}
+
+/// Displayed as `PhantomData`, or `null` in JSON, whether or not `T` is displayable.
+impl<T: ?Sized> ToDisplay for PhantomData<T> {
+    type Displayer<'a>
+        = DisplayUnit
+    where
+        Self: 'a;
+
+    fn display_with_context(&self, context: Context) -> Self::Displayer<'_> {
+        DisplayUnit::new("PhantomData", context)
+    }
+}
+
+// Types that are not a point in time are displayed with their own `Display`, and ignore the
+// timezone and the time format of the `Context`: `NaiveDate` as `2024-12-28` and `TimeDelta` in
+// ISO 8601 as `PT1.5S`. A calendar date has no time of day, so converting it to a point in time
+// would make the format show an arbitrary midnight, and the timezone could shift it to another
+// day.
+#[cfg(feature = "std-time")]
+impl_to_display_text!(chrono::NaiveDate, chrono::TimeDelta);
+
+// `time::Duration` is displayed with its own `Display`, e.g., `1.5s`.
+#[cfg(feature = "time-crate")]
+impl_to_display_text!(time::Duration);
@@ -0,0 +1,40 @@
+#![cfg(feature = "anyhow")]
+
+use std::io;
+
+use anyhow::Context as _;
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+fn load() -> anyhow::Result<u64> {
+    let err = io::Error::new(io::ErrorKind::NotFound, "no such file");
+    Err(err).context("open file").context("load config")
+}
+
+#[test]
+fn test_display_anyhow() {
+    // Backtrace capturing is decided once per process, so this is the only test in this file.
+    std::env::set_var("RUST_LIB_BACKTRACE", "1");
+
+    let result = load();
+
+    assert_eq!(result.display().to_string(), "Err(load config)");
+    assert_eq!(
+        result.display().verbose().to_string(),
+        "Err(load config: open file: no such file)"
+    );
+    assert_eq!(
+        result.display().verbose().limit_items(1).to_string(),
+        "Err(load config: open file: ...)"
+    );
+
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.display().verbose().to_string(),
+        "load config: open file: no such file"
+    );
+
+    let with_backtrace = err.display().with_backtrace().to_string();
+    assert!(with_backtrace.starts_with("load config\nbacktrace:\n"));
+    assert!(with_backtrace.contains("test_display_anyhow"));
+}
@@ -0,0 +1,48 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::AtomicU8;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_atomic() {
+    assert_eq!(AtomicBool::new(true).display().to_string(), "true");
+    assert_eq!(AtomicI64::new(-3).display().to_string(), "-3");
+    assert_eq!(AtomicU8::new(7).display().to_string(), "7");
+    assert_eq!(format!("{:>3}", AtomicUsize::new(7).display()), "  7");
+}
+
+#[test]
+fn test_display_atomic_ordering() {
+    let a = AtomicUsize::new(42);
+
+    for ordering in [
+        Ordering::Relaxed,
+        Ordering::Acquire,
+        Ordering::SeqCst,
+        // Not valid for a load; replaced with a valid ordering.
+        Ordering::Release,
+        Ordering::AcqRel,
+    ] {
+        assert_eq!(a.display().with_atomic_ordering(ordering).to_string(), "42");
+    }
+}
+
+#[test]
+fn test_display_atomic_ptr() {
+    let mut x = 1u32;
+    let p: *mut u32 = &mut x;
+    let a = AtomicPtr::new(p);
+
+    assert_eq!(a.display().to_string(), format!("{:p}", p));
+}
+
+#[test]
+fn test_display_atomic_in_collection() {
+    let v = [AtomicU8::new(1), AtomicU8::new(2)];
+    assert_eq!(v.display().to_string(), "[1, 2]");
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use std::collections::BTreeMap;
 
 use to_display::DisplayConfig;
@@ -19,3 +21,28 @@ fn test_display_btreemap() {
     let display = display.limit_items(2);
     assert_eq!(display.to_string(), "{None: [3], Some(1): [1], ...}");
 }
+
+#[test]
+fn test_display_btreemap_delimiters() {
+    let map = BTreeMap::from([("a", 1u8), ("b", 2), ("c", 3)]);
+
+    let logfmt = map
+        .display()
+        .with_map_brackets("", "")
+        .with_separator(" ")
+        .with_key_value_separator("=");
+    assert_eq!(logfmt.to_string(), "a=1 b=2 c=3");
+
+    let logfmt = logfmt.limit_items(2);
+    assert_eq!(logfmt.to_string(), "a=1 b=2 ...");
+
+    // Delimiters propagate to nested collections.
+    let map = BTreeMap::from([("a", vec![1u8, 2]), ("b", vec![])]);
+    let d = map
+        .display()
+        .with_map_brackets("<", ">")
+        .with_list_brackets("(", ")")
+        .with_separator("; ")
+        .with_key_value_separator(" => ");
+    assert_eq!(d.to_string(), "<a => (1; 2); b => ()>");
+}
@@ -0,0 +1,99 @@
+use to_display::ByteFormat;
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_bytes_list() {
+    let b: &[u8] = b"ab\x00";
+    assert_eq!(b.display().to_string(), "[97, 98, 0]");
+    assert_eq!(b.display().limit_items(2).to_string(), "[97, 98, ...]");
+    assert_eq!(
+        b.display().with_byte_format(ByteFormat::List).to_string(),
+        "[97, 98, 0]"
+    );
+}
+
+#[test]
+fn test_display_bytes_hex() {
+    let b: &[u8] = b"jk\x00";
+    assert_eq!(b.display().use_hex_bytes().to_string(), "6a6b00");
+    assert_eq!(b.display().use_upper_hex_bytes().to_string(), "6A6B00");
+    assert_eq!(
+        b.display().use_hex_bytes().limit_items(2).to_string(),
+        "6a6b..."
+    );
+    assert_eq!(b"".display().use_hex_bytes().to_string(), "");
+}
+
+#[test]
+fn test_display_bytes_escaped() {
+    let b: &[u8] = b"ab\x00\"\\\n\t\r\xff ~";
+    assert_eq!(
+        b.display().use_escaped_bytes().to_string(),
+        r#"b"ab\x00\"\\\n\t\r\xff ~""#
+    );
+    assert_eq!(
+        b.display().use_escaped_bytes().limit_items(2).to_string(),
+        r#"b"ab"..."#
+    );
+}
+
+#[test]
+fn test_display_bytes_base64() {
+    let cases: [(&[u8], &str); 7] = [
+        (b"", ""),
+        (b"f", "Zg=="),
+        (b"fo", "Zm8="),
+        (b"foo", "Zm9v"),
+        (b"foob", "Zm9vYg=="),
+        (b"fooba", "Zm9vYmE="),
+        (b"foobar", "Zm9vYmFy"),
+    ];
+
+    for (b, want) in cases {
+        assert_eq!(b.display().use_base64_bytes().to_string(), want);
+    }
+
+    let b: &[u8] = b"foobar";
+    assert_eq!(
+        b.display().use_base64_bytes().limit_items(3).to_string(),
+        "Zm9v..."
+    );
+}
+
+#[test]
+fn test_display_bytes_hexdump() {
+    let b: &[u8] = b"hello world\n0123456789";
+    assert_eq!(
+        b.display().use_hexdump_bytes().to_string(),
+        [
+            "00000000  68 65 6c 6c 6f 20 77 6f  72 6c 64 0a 30 31 32 33  |hello world.0123|",
+            "00000010  34 35 36 37 38 39                                 |456789|",
+        ]
+        .join("\n")
+    );
+
+    assert_eq!(
+        b.display().use_hexdump_bytes().limit_items(3).to_string(),
+        [
+            "00000000  68 65 6c                                          |hel|",
+            "...",
+        ]
+        .join("\n")
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_display_vec_u8() {
+    let v = vec![0xde_u8, 0xad, 0xbe, 0xef];
+    assert_eq!(v.display().use_hex_bytes().to_string(), "deadbeef");
+
+    // Other element types ignore the byte format.
+    let v = vec![1u16, 2];
+    assert_eq!(v.display().use_hex_bytes().to_string(), "[1, 2]");
+
+    // The byte format propagates to nested byte slices.
+    let v = vec![Some(vec![1u8, 2]), None];
+    assert_eq!(v.display().use_hex_bytes().to_string(), "[0102, -]");
+}
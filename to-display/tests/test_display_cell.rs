@@ -0,0 +1,26 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_cell() {
+    let c = Cell::new(Some(3u64));
+    assert_eq!(c.display().to_string(), "3");
+    assert_eq!(c.display().verbose().to_string(), "Some(3)");
+}
+
+#[test]
+fn test_display_ref_cell() {
+    let c = RefCell::new(None::<u32>);
+    assert_eq!(c.display().verbose().to_string(), "None");
+
+    {
+        let _r = c.borrow();
+        assert_eq!(c.display().to_string(), "-");
+    }
+
+    let _w = c.borrow_mut();
+    assert_eq!(c.display().to_string(), "<locked>");
+}
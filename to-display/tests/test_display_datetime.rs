@@ -0,0 +1,95 @@
+#![cfg(feature = "std-time")]
+
+use std::time::Duration;
+use std::time::Instant;
+use std::time::UNIX_EPOCH;
+
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::TimeDelta;
+use chrono::TimeZone;
+use chrono::Utc;
+use to_display::scoped_clock;
+use to_display::DisplayConfig;
+use to_display::FixedClock;
+use to_display::ToDisplay;
+use to_display::UtcOffset;
+
+#[test]
+fn test_display_datetime() {
+    let utc: DateTime<Utc> = Utc.with_ymd_and_hms(2024, 12, 28, 15, 33, 20).unwrap();
+    let fixed: DateTime<FixedOffset> = utc.with_timezone(&FixedOffset::east_opt(3600).unwrap());
+
+    // The original offset is replaced with the timezone of the context.
+    assert_eq!(utc.display().use_utc_time().to_string(), "15:33:20.000000");
+    assert_eq!(
+        fixed.display().use_utc_time().to_string(),
+        "15:33:20.000000"
+    );
+    assert_eq!(
+        fixed
+            .display()
+            .use_fixed_offset(UtcOffset::from_seconds(8 * 3600).unwrap())
+            .use_rfc3339_time()
+            .to_string(),
+        "2024-12-28T23:33:20.000000+08:00"
+    );
+
+    // A naive date time is displayed at its own wall-clock time, whatever the timezone.
+    let naive = utc.naive_utc();
+    assert_eq!(
+        naive.display().use_utc_time().use_full_time().to_string(),
+        "2024-12-28T15:33:20.000000Z+0000"
+    );
+    assert_eq!(naive.display().to_string(), "15:33:20.000000");
+    assert_eq!(
+        naive
+            .display()
+            .use_fixed_offset(UtcOffset::from_seconds(10 * 3600).unwrap())
+            .use_date_only()
+            .to_string(),
+        "2024-12-28"
+    );
+}
+
+#[test]
+fn test_display_date_and_time_delta() {
+    // A date and a duration are not points in time: the timezone and the time format do not
+    // apply to them.
+    let date = NaiveDate::from_ymd_opt(2024, 12, 28).unwrap();
+    assert_eq!(date.display().to_string(), "2024-12-28");
+    assert_eq!(
+        date.display()
+            .use_fixed_offset(UtcOffset::from_seconds(-5 * 3600).unwrap())
+            .use_unix_seconds()
+            .to_string(),
+        "2024-12-28"
+    );
+    assert_eq!(date.display().use_rfc3339_time().to_string(), "2024-12-28");
+
+    let delta = TimeDelta::milliseconds(1500);
+    assert_eq!(delta.display().to_string(), "PT1.5S");
+    assert_eq!(
+        delta.display().use_utc_time().use_full_time().to_string(),
+        "PT1.5S"
+    );
+}
+
+#[test]
+fn test_display_datetime_and_instant_consistently() {
+    let now = Instant::now();
+
+    // 2024-12-28T15:33:20Z
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(1_735_400_000))
+        .with_local_offset(UtcOffset::from_seconds(8 * 3600).unwrap());
+    let _clock = scoped_clock(clock);
+
+    let datetime = Utc.with_ymd_and_hms(2024, 12, 28, 15, 33, 20).unwrap();
+
+    let instants = vec![now];
+    let datetimes = vec![datetime];
+
+    assert_eq!(instants.display().to_string(), "[23:33:20.000000]");
+    assert_eq!(datetimes.display().to_string(), "[23:33:20.000000]");
+}
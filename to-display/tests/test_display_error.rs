@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[derive(Debug)]
+struct Wrapped {
+    msg: &'static str,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl Wrapped {
+    fn new(msg: &'static str, source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Wrapped {
+            msg,
+            source: Some(source.into()),
+        }
+    }
+}
+
+impl fmt::Display for Wrapped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl Error for Wrapped {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_ref().map(|e| e.as_ref() as _)
+    }
+}
+
+#[test]
+fn test_display_error_chain() {
+    let io_err = io::Error::new(io::ErrorKind::NotFound, "no such file");
+    let err: Box<dyn Error + Send + Sync> = Box::new(Wrapped::new(
+        "load config",
+        Wrapped::new("open file", io_err),
+    ));
+
+    assert_eq!(err.display().to_string(), "load config");
+    assert_eq!(
+        err.display().verbose().to_string(),
+        "load config: open file: no such file"
+    );
+    assert_eq!(
+        err.display().verbose().limit_items(1).to_string(),
+        "load config: open file: ..."
+    );
+}
+
+#[test]
+fn test_display_result_error_chain() {
+    let result: Result<u64, Box<dyn Error + Send + Sync>> = Err(Box::new(Wrapped::new(
+        "load config",
+        io::Error::new(io::ErrorKind::NotFound, "no such file"),
+    )));
+
+    assert_eq!(result.display().to_string(), "Err(load config)");
+    assert_eq!(
+        result.display().verbose().to_string(),
+        "Err(load config: no such file)"
+    );
+
+    let result: Result<u64, io::Error> = Err(io::Error::new(io::ErrorKind::Other, "boom"));
+    assert_eq!(result.display().verbose().to_string(), "Err(boom)");
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "eyre")]
+
+use std::io;
+
+use eyre::WrapErr;
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_eyre() {
+    let err = io::Error::new(io::ErrorKind::NotFound, "no such file");
+    let result: eyre::Result<u64> = Err(err).wrap_err("open file").wrap_err("load config");
+
+    assert_eq!(result.display().to_string(), "Err(load config)");
+    assert_eq!(
+        result.display().verbose().to_string(),
+        "Err(load config: open file: no such file)"
+    );
+
+    let err = result.unwrap_err();
+    assert_eq!(err.display().with_backtrace().to_string(), "load config");
+}
@@ -0,0 +1,38 @@
+use to_display::display_fn;
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_fn() {
+    let d = display_fn(|f, ctx| write!(f, "max={}", ctx.max_items()));
+
+    assert_eq!(d.to_string(), "max=32");
+    assert_eq!(d.limit_items(3).to_string(), "max=3");
+}
+
+#[test]
+fn test_display_fn_timezone() {
+    let d = display_fn(|f, ctx| write!(f, "{}/{}", ctx.is_local_time(), ctx.is_utc_time()));
+
+    assert_eq!(d.to_string(), "true/false");
+    assert_eq!(d.use_utc_time().to_string(), "false/true");
+}
+
+#[test]
+fn test_display_fn_inherits_context() {
+    let d = display_fn(|f, ctx| write!(f, "a{}", if ctx.verbose() { "!" } else { "" }));
+    let v = [&d, &d];
+
+    assert_eq!(v.display().to_string(), "[a, a]");
+    assert_eq!(v.display().verbose().to_string(), "[a!, a!]");
+    assert_eq!(Some(&d).display().verbose().to_string(), "Some(a!)");
+}
+
+#[test]
+fn test_display_fn_nested() {
+    let x = Some(1u8);
+    let d = display_fn(|f, ctx| write!(f, "x={}", x.display_with_context(ctx)));
+
+    assert_eq!(d.to_string(), "x=1");
+    assert_eq!(d.verbose().to_string(), "x=Some(1)");
+}
@@ -1,9 +1,18 @@
 #![cfg(feature = "std-time")]
 
+use std::sync::OnceLock;
+use std::time::Duration;
 use std::time::Instant;
+use std::time::UNIX_EPOCH;
 
+use chrono::FixedOffset;
+use to_display::scoped_clock;
 use to_display::DisplayConfig;
+use to_display::FixedClock;
+use to_display::StrftimeFormat;
+use to_display::TimeFormat;
 use to_display::ToDisplay;
+use to_display::UtcOffset;
 
 #[test]
 fn test_display_instant() {
@@ -28,6 +37,229 @@ fn test_display_instant() {
     assert!(re.is_match(&display),);
 }
 
+#[test]
+fn test_display_instant_with_fixed_clock() {
+    let now = Instant::now();
+
+    // 2024-12-28T15:33:20Z
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(1_735_400_000))
+        .with_local_offset(UtcOffset::from_seconds(8 * 3600).unwrap());
+    let _clock = scoped_clock(clock);
+
+    let t = now + Duration::from_millis(1500);
+    assert_eq!(t.display().to_string(), "23:33:21.500000");
+    assert_eq!(t.display().use_utc_time().to_string(), "15:33:21.500000");
+    assert_eq!(
+        t.display().use_full_time().to_string(),
+        "2024-12-28T23:33:21.500000Z+0800"
+    );
+
+    let t = now - Duration::from_secs(60);
+    assert_eq!(t.display().to_string(), "23:32:20.000000");
+
+    let v = vec![Some(now)];
+    assert_eq!(v.display().verbose().to_string(), "[Some(23:33:20.000000)]");
+}
+
+#[test]
+fn test_display_instant_clock_precedence() {
+    static CLOCK: OnceLock<FixedClock> = OnceLock::new();
+
+    let now = Instant::now();
+
+    // 1970-01-01T01:00:00Z
+    let clock = CLOCK.get_or_init(|| FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(3600)));
+
+    {
+        // 2024-12-28T15:33:20Z
+        let _outer = scoped_clock(FixedClock::new(
+            now,
+            UNIX_EPOCH + Duration::from_secs(1_735_400_000),
+        ));
+        assert_eq!(now.display().use_utc_time().to_string(), "15:33:20.000000");
+
+        {
+            let _inner = scoped_clock(FixedClock::new(now, UNIX_EPOCH));
+            assert_eq!(now.display().use_utc_time().to_string(), "00:00:00.000000");
+
+            // A clock set in the context takes precedence, and is inherited by nested values.
+            let v = vec![now];
+            assert_eq!(
+                v.display().with_clock(clock).use_utc_time().to_string(),
+                "[01:00:00.000000]"
+            );
+        }
+
+        // Dropping a guard restores the previous clock.
+        assert_eq!(now.display().use_utc_time().to_string(), "15:33:20.000000");
+    }
+
+    // A scoped clock is only installed on the thread that created it.
+    let _clock = scoped_clock(FixedClock::new(now, UNIX_EPOCH));
+    let other = std::thread::spawn(move || now.display().use_utc_time().to_string());
+    assert_ne!(other.join().unwrap(), "00:00:00.000000");
+}
+
+#[test]
+fn test_display_instant_timezone() {
+    let now = Instant::now();
+
+    // 2024-12-28T15:33:20Z
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(1_735_400_000));
+    let _clock = scoped_clock(clock);
+
+    let d = now.display();
+
+    let d = d.use_fixed_offset(UtcOffset::from_seconds(-5 * 3600).unwrap());
+    assert_eq!(d.to_string(), "10:33:20.000000");
+
+    let d = d.with_timezone("+09:30".parse::<FixedOffset>().unwrap());
+    assert_eq!(
+        d.use_full_time().to_string(),
+        "2024-12-29T01:03:20.000000Z+0930"
+    );
+
+    // An offset of 24 hours or more is rejected up front.
+    let err = UtcOffset::from_seconds(-24 * 3600).unwrap_err();
+    assert_eq!(err.seconds(), -86400);
+    assert_eq!(
+        err.to_string(),
+        "UTC offset of -86400 seconds is out of range, it must be less than 24 hours"
+    );
+    assert!(UtcOffset::from_seconds(86399).is_ok());
+}
+
+#[test]
+fn test_display_instant_time_format() {
+    let now = Instant::now();
+
+    // 2024-12-28T15:33:20Z
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(1_735_400_000));
+    let _clock = scoped_clock(clock);
+
+    // A format loaded at runtime does not need to be `'static`.
+    let loaded = String::from("%Y/%m/%d %H:%M");
+    let fmt = StrftimeFormat::parse(&loaded).unwrap();
+    assert_eq!(fmt, StrftimeFormat::parse(&loaded).unwrap());
+    let d = now.display().with_time_format_spec(fmt);
+    assert_eq!(d.to_string(), "2024/12/28 15:33");
+
+    let d = d.try_with_time_format("%m-%d").unwrap();
+    assert_eq!(d.to_string(), "12-28");
+
+    // A `'static` format string, as accepted before formats could be parsed.
+    let d = || now.display().use_utc_time();
+    assert_eq!(d().with_time_format("%d %H").to_string(), "28 15");
+    assert_eq!(
+        d().with_time_format("SIMPLE").to_string(),
+        "15:33:20.000000"
+    );
+    assert_eq!(
+        d().with_time_format("FULL").to_string(),
+        "2024-12-28T15:33:20.000000Z+0000"
+    );
+    assert_eq!(
+        d().with_time_format("%H:%Q").to_string(),
+        "<invalid time format \"%H:%Q\">"
+    );
+
+    let err = StrftimeFormat::parse("%H:%Q").unwrap_err();
+    assert_eq!(err.format(), "%H:%Q");
+    assert_eq!(
+        err.to_string(),
+        "invalid time format \"%H:%Q\": bad or unsupported format string"
+    );
+}
+
+#[test]
+fn test_display_instant_time_format_presets() {
+    let now = Instant::now();
+
+    // 2024-12-28T15:33:20.123456Z
+    let clock = FixedClock::new(
+        now,
+        UNIX_EPOCH + Duration::from_secs(1_735_400_000) + Duration::from_micros(123_456),
+    )
+    .with_local_offset(UtcOffset::from_seconds(8 * 3600).unwrap());
+    let _clock = scoped_clock(clock);
+
+    let d = || now.display();
+
+    assert_eq!(
+        d().use_rfc3339_time().to_string(),
+        "2024-12-28T23:33:20.123456+08:00"
+    );
+    assert_eq!(
+        d().use_rfc3339_time().use_utc_time().to_string(),
+        "2024-12-28T15:33:20.123456Z"
+    );
+    assert_eq!(
+        d().use_rfc2822_time().to_string(),
+        "Sat, 28 Dec 2024 23:33:20 +0800"
+    );
+    assert_eq!(d().use_unix_seconds().to_string(), "1735400000");
+    assert_eq!(d().use_unix_millis().to_string(), "1735400000123");
+    assert_eq!(d().use_unix_micros().to_string(), "1735400000123456");
+    assert_eq!(d().use_date_only().to_string(), "2024-12-28");
+    assert_eq!(
+        d().use_full_time().to_string(),
+        "2024-12-28T23:33:20.123456Z+0800"
+    );
+    assert_eq!(
+        d().with_time_format_spec(TimeFormat::Simple).to_string(),
+        "23:33:20.123456"
+    );
+}
+
+#[test]
+fn test_display_instant_json() {
+    let now = Instant::now();
+
+    // 2024-12-28T15:33:20.123456Z
+    let clock = FixedClock::new(
+        now,
+        UNIX_EPOCH + Duration::from_secs(1_735_400_000) + Duration::from_micros(123_456),
+    )
+    .with_local_offset(UtcOffset::from_seconds(8 * 3600).unwrap());
+    let _clock = scoped_clock(clock);
+
+    // In JSON, a time is an RFC 3339 string whatever the time format is.
+    assert_eq!(
+        now.display().json().to_string(),
+        r#""2024-12-28T23:33:20.123456+08:00""#
+    );
+    assert_eq!(
+        vec![now]
+            .display()
+            .use_unix_seconds()
+            .use_utc_time()
+            .json()
+            .to_string(),
+        r#"["2024-12-28T15:33:20.123456Z"]"#
+    );
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_display_instant_named_timezone() {
+    let now = Instant::now();
+
+    // 2024-12-28T15:33:20Z
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(1_735_400_000));
+    let _clock = scoped_clock(clock);
+
+    let d = now.display();
+
+    let d = d.use_named_timezone(chrono_tz::Asia::Shanghai);
+    assert_eq!(d.to_string(), "23:33:20.000000");
+
+    let d = d.with_timezone(chrono_tz::America::New_York);
+    assert_eq!(
+        d.try_with_time_format("%H:%M %Z").unwrap().to_string(),
+        "10:33 EST"
+    );
+}
+
 #[cfg(feature = "tokio-time")]
 #[test]
 fn test_display_tokio_instant() {
@@ -45,3 +277,27 @@ fn test_display_tokio_instant() {
     let re = regex::Regex::new(r"^\d{2}:\d{2}:\d{2}\.\d{6}$").unwrap();
     assert!(re.is_match(&display),);
 }
+#[test]
+fn test_display_instant_out_of_range() {
+    let now = Instant::now();
+
+    // 9999-12-30T00:00:00Z, and one year later.
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(253_402_128_000));
+    let _clock = scoped_clock(clock);
+    let later = now + Duration::from_secs(365 * 86_400);
+
+    assert_eq!(
+        now.display().use_utc_time().use_rfc3339_time().to_string(),
+        "9999-12-30T00:00:00.000000Z"
+    );
+
+    // chrono supports years past 9999.
+    assert_eq!(
+        later
+            .display()
+            .use_utc_time()
+            .use_rfc3339_time()
+            .to_string(),
+        "10000-12-29T00:00:00.000000Z"
+    );
+}
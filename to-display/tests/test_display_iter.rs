@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+use to_display::display_iter;
+use to_display::display_iter_once;
+use to_display::DisplayConfig;
+
+#[test]
+fn test_display_iter() {
+    let v = [1, 2, 3, 4, 5, 6];
+
+    let evens = v.iter().filter(|x| *x % 2 == 0);
+    assert_eq!(display_iter(evens.clone()).to_string(), "[2, 4, 6]");
+    assert_eq!(
+        display_iter(evens).limit_items(2).to_string(),
+        "[2, 4, ...]"
+    );
+
+    // An exact fit is not truncated.
+    assert_eq!(
+        display_iter(v.iter()).limit_items(6).to_string(),
+        "[1, 2, 3, 4, 5, 6]"
+    );
+
+    assert_eq!(display_iter(0..0).to_string(), "[]");
+    assert_eq!(
+        display_iter(0..).limit_items(3).to_string(),
+        "[0, 1, 2, ...]"
+    );
+
+    // Can be formatted more than once.
+    let d = display_iter(&v[..2]);
+    assert_eq!(d.to_string(), "[1, 2]");
+    assert_eq!(d.to_string(), "[1, 2]");
+}
+
+#[test]
+fn test_display_iter_map_values() {
+    let m = BTreeMap::from([(1, Some("a")), (2, None), (3, Some("c"))]);
+
+    assert_eq!(display_iter(m.values()).to_string(), "[a, -, c]");
+    assert_eq!(
+        display_iter(m.values()).verbose().to_string(),
+        "[Some(a), None, Some(c)]"
+    );
+}
+
+#[test]
+fn test_display_iter_once() {
+    let d = display_iter_once((1..).map(|x| x * 10)).limit_items(2);
+    assert_eq!(d.to_string(), "[10, 20, ...]");
+    assert_eq!(d.to_string(), "<consumed>");
+}
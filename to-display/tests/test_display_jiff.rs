@@ -0,0 +1,88 @@
+#![cfg(feature = "jiff")]
+
+use std::time::Duration;
+use std::time::Instant;
+use std::time::UNIX_EPOCH;
+
+use jiff::tz::Offset;
+use jiff::Timestamp;
+use jiff::Zoned;
+use to_display::scoped_clock;
+use to_display::DisplayConfig;
+use to_display::FixedClock;
+use to_display::ToDisplay;
+use to_display::UtcOffset;
+
+#[test]
+fn test_display_timestamp() {
+    let t: Timestamp = "2024-12-28T15:33:20.123456Z".parse().unwrap();
+
+    assert_eq!(t.display().use_utc_time().to_string(), "15:33:20.123456");
+    assert_eq!(
+        t.display()
+            .use_fixed_offset(Offset::constant(-5).try_into().unwrap())
+            .use_rfc3339_time()
+            .to_string(),
+        "2024-12-28T10:33:20.123456-05:00"
+    );
+    assert_eq!(
+        t.display().use_utc_time().use_rfc2822_time().to_string(),
+        "Sat, 28 Dec 2024 15:33:20 +0000"
+    );
+    assert_eq!(
+        t.display().use_unix_micros().to_string(),
+        "1735400000123456"
+    );
+}
+
+#[test]
+fn test_display_zoned() {
+    let z: Zoned = "2024-12-28T23:33:20+08:00[Asia/Shanghai]".parse().unwrap();
+
+    // The zone of the value is replaced with the timezone of the context.
+    assert_eq!(
+        z.display().use_utc_time().use_rfc3339_time().to_string(),
+        "2024-12-28T15:33:20.000000Z"
+    );
+}
+
+#[test]
+fn test_display_instant_with_jiff() {
+    let now = Instant::now();
+
+    // 2024-12-28T15:33:20Z
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(1_735_400_000))
+        .with_local_offset(UtcOffset::from_seconds(8 * 3600).unwrap());
+    let _clock = scoped_clock(clock);
+
+    assert_eq!(now.display().use_date_only().to_string(), "2024-12-28");
+}
+#[test]
+fn test_display_instant_with_jiff_out_of_range() {
+    let now = Instant::now();
+
+    // 9999-12-30T00:00:00Z, and one year later.
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(253_402_128_000));
+    let _clock = scoped_clock(clock);
+    let later = now + Duration::from_secs(365 * 86_400);
+
+    assert_eq!(
+        now.display().use_utc_time().use_rfc3339_time().to_string(),
+        "9999-12-30T00:00:00.000000Z"
+    );
+
+    // Out of the range of `jiff`, unless chrono is enabled.
+    #[cfg(not(feature = "std-time"))]
+    assert_eq!(
+        later
+            .display()
+            .use_utc_time()
+            .use_rfc3339_time()
+            .to_string(),
+        "<out of range>"
+    );
+    assert_eq!(
+        later.display().use_unix_seconds().to_string(),
+        "253433664000"
+    );
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "std")]
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::net::Ipv4Addr;
+use std::path::Path;
+
+use to_display::display_iter;
+use to_display::DisplayConfig;
+use to_display::OutputStyle;
+use to_display::ToDisplay;
+
+#[derive(ToDisplay)]
+#[to_display(fields)]
+struct Peer {
+    addr: Ipv4Addr,
+    port: u16,
+    name: Option<String>,
+}
+
+#[derive(ToDisplay)]
+struct Id(u64);
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "id-{}", self.0)
+    }
+}
+
+#[test]
+fn test_json_scalars() {
+    // Integers and `bool` are displayed the same way in JSON.
+    assert_eq!(vec![1u64].display().json().to_string(), "[1]");
+    assert_eq!(vec![true].display().json().to_string(), "[true]");
+    assert_eq!((-1.5f64).display().json().to_string(), "-1.5");
+    assert_eq!(f64::NAN.display().json().to_string(), "null");
+    assert_eq!(f32::INFINITY.display().json().to_string(), "null");
+    assert_eq!(().display().json().to_string(), "null");
+    assert_eq!(().display().to_string(), "()");
+    assert_eq!(
+        std::cmp::Ordering::Less.display().json().to_string(),
+        r#""Less""#
+    );
+    assert_eq!(
+        Ipv4Addr::LOCALHOST.display().json().to_string(),
+        r#""127.0.0.1""#
+    );
+    assert_eq!(Id(3).display().json().to_string(), r#""id-3""#);
+    assert_eq!(Id(3).display().to_string(), "id-3");
+}
+
+#[test]
+fn test_json_strings() {
+    let s = "a\"b\\c\n\u{1}é";
+    assert_eq!(s.display().json().to_string(), r#""a\"b\\c\n\u0001é""#);
+    assert_eq!('"'.display().json().to_string(), r#""\"""#);
+    assert_eq!(c"a\xff".display().json().to_string(), "\"a\u{FFFD}\"");
+
+    // `escape_strings` does not apply in JSON.
+    assert_eq!(
+        "a\n".display().json().escape_strings().to_string(),
+        r#""a\n""#
+    );
+
+    let p = Path::new("/tmp/a\"b");
+    assert_eq!(
+        ToDisplay::display(p).json().quote_paths().to_string(),
+        r#""/tmp/a\"b""#
+    );
+}
+
+#[test]
+fn test_json_collections() {
+    let v = vec![Some(1), None, Some(3)];
+    assert_eq!(v.display().json().to_string(), "[1,null,3]");
+    assert_eq!(v.display().json().verbose().to_string(), "[1,null,3]");
+    assert_eq!(
+        v.display().json().limit_items(1).to_string(),
+        r#"[1,"..."]"#
+    );
+    assert_eq!(v.display().json().limit_items(0).to_string(), r#"["..."]"#);
+
+    // Separators and brackets do not apply in JSON.
+    assert_eq!(
+        v.display()
+            .with_separator(" ")
+            .with_list_brackets("", "")
+            .json()
+            .to_string(),
+        "[1,null,3]"
+    );
+
+    let m = BTreeMap::from([(1, vec!["a"]), (2, vec![])]);
+    assert_eq!(m.display().json().to_string(), r#"{"1":["a"],"2":[]}"#);
+    assert_eq!(
+        m.display().json().limit_items(1).to_string(),
+        r#"{"1":["a"],"...":"..."}"#
+    );
+
+    let m = BTreeMap::from([("k\"", 1)]);
+    assert_eq!(m.display().json().to_string(), r#"{"k\"":1}"#);
+
+    assert_eq!(display_iter(1..4).json().to_string(), "[1,2,3]");
+}
+
+#[test]
+fn test_json_bytes() {
+    let b: &[u8] = b"a\"\x00";
+    assert_eq!(b.display().json().to_string(), "[97,34,0]");
+    assert_eq!(
+        b.display().json().use_hex_bytes().to_string(),
+        r#""612200""#
+    );
+    assert_eq!(
+        b.display().json().use_escaped_bytes().to_string(),
+        r#""b\"a\\\"\\x00\"""#
+    );
+}
+
+#[test]
+fn test_json_result_and_error() {
+    let r: Result<u8, &str> = Ok(1);
+    assert_eq!(r.display().json().to_string(), r#"{"Ok":1}"#);
+    assert_eq!(r.display().json().bare_ok().to_string(), r#"{"Ok":1}"#);
+
+    let r: Result<u8, &str> = Err("bad \"x\"");
+    assert_eq!(r.display().json().to_string(), r#"{"Err":"bad \"x\""}"#);
+
+    let e = io::Error::other("disk \"full\"");
+    assert_eq!(e.display().json().to_string(), r#""disk \"full\"""#);
+}
+
+#[test]
+fn test_json_struct() {
+    let p = Peer {
+        addr: Ipv4Addr::new(10, 0, 0, 1),
+        port: 80,
+        name: None,
+    };
+    assert_eq!(
+        p.display().json().to_string(),
+        r#"{"addr":"10.0.0.1","port":80,"name":null}"#
+    );
+
+    let m = BTreeMap::from([("p", p)]);
+    assert_eq!(
+        m.display().with_output_style(OutputStyle::Json).to_string(),
+        r#"{"p":{"addr":"10.0.0.1","port":80,"name":null}}"#
+    );
+}
+
+#[test]
+fn test_json_misc() {
+    assert_eq!((3..7).display().json().to_string(), r#""[3, 7)""#);
+
+    let c = RefCell::new(1);
+    let _w = c.borrow_mut();
+    assert_eq!(c.display().json().to_string(), r#""<locked>""#);
+}
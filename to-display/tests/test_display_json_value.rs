@@ -0,0 +1,99 @@
+#![cfg(feature = "serde_json")]
+
+use serde_json::json;
+use serde_json::Value;
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+fn body() -> Value {
+    json!({
+        "id": 7,
+        "ok": true,
+        "name": "a \"quoted\" name",
+        "items": [1, 2.5, null, "x"],
+        "user": {"id": 3, "roles": ["admin", "dev"]},
+    })
+}
+
+#[test]
+fn test_display_json_value() {
+    let v = body();
+    assert_eq!(
+        v.display().to_string(),
+        r#"{id: 7, items: [1, 2.5, null, x], name: a "quoted" name, ok: true, user: {id: 3, roles: [admin, dev]}}"#
+    );
+
+    assert_eq!(Value::Null.display().to_string(), "null");
+    assert_eq!(json!(-1.5).display().to_string(), "-1.5");
+    assert_eq!(
+        json!("a\nb").display().escape_strings().to_string(),
+        r#""a\nb""#
+    );
+    assert_eq!(json!([]).display().to_string(), "[]");
+    assert_eq!(json!({}).display().to_string(), "{}");
+
+    // A `Map` is displayed the same way as an object.
+    let m = v["user"].as_object().unwrap();
+    assert_eq!(m.display().to_string(), "{id: 3, roles: [admin, dev]}");
+}
+
+#[test]
+fn test_display_json_value_limits() {
+    let v = body();
+    assert_eq!(v.display().limit_items(1).to_string(), "{id: 7, ...}");
+    assert_eq!(
+        v["items"].display().limit_items(2).to_string(),
+        "[1, 2.5, ...]"
+    );
+    assert_eq!(v["name"].display().limit_str_len(3).to_string(), "a \"...",);
+
+    let big = Value::Array((0..1000).map(Value::from).collect());
+    assert_eq!(big.display().limit_items(3).to_string(), "[0, 1, 2, ...]");
+}
+
+#[test]
+fn test_display_json_value_json() {
+    let v = body();
+    assert_eq!(v.display().json().to_string(), v.to_string());
+    assert_eq!(
+        v.display()
+            .json()
+            .limit_items(2)
+            .limit_str_len(4)
+            .to_string(),
+        r#"{"id":7,"items":[1,2.5,"..."],"...":"..."}"#
+    );
+    assert_eq!(
+        v["name"].display().json().limit_str_len(4).to_string(),
+        r#""a \"q...""#
+    );
+}
+
+#[test]
+fn test_display_json_value_logfmt() {
+    let v = body();
+    assert_eq!(
+        v.display().logfmt().to_string(),
+        r#"id=7 items="[1, 2.5, null, x]" name="a \"quoted\" name" ok=true user.id=3 user.roles="[admin, dev]""#
+    );
+    assert_eq!(
+        v.display().logfmt().limit_items(1).to_string(),
+        r#"id=7 ...=..."#
+    );
+
+    // Keys are displayed the same way as the keys of a `BTreeMap`.
+    let v = json!({"a b": 1, "long key": 2});
+    let m = std::collections::BTreeMap::from([("a b", 1), ("long key", 2)]);
+    assert_eq!(
+        v.display()
+            .logfmt()
+            .escape_strings()
+            .limit_str_len(3)
+            .to_string(),
+        m.display()
+            .logfmt()
+            .escape_strings()
+            .limit_str_len(3)
+            .to_string()
+    );
+}
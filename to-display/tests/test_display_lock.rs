@@ -0,0 +1,52 @@
+#![cfg(feature = "std")]
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_mutex() {
+    let m = Mutex::new(Some(1u32));
+    assert_eq!(m.display().to_string(), "1");
+    assert_eq!(m.display().verbose().to_string(), "Some(1)");
+
+    let guard = m.lock().unwrap();
+    assert_eq!(m.display().to_string(), "<locked>");
+    drop(guard);
+
+    assert_eq!(m.display().to_string(), "1");
+}
+
+#[test]
+fn test_display_rw_lock() {
+    let l = RwLock::new(5u8);
+    assert_eq!(l.display().to_string(), "5");
+
+    {
+        let _r = l.read().unwrap();
+        assert_eq!(l.display().to_string(), "5");
+    }
+
+    let _w = l.write().unwrap();
+    assert_eq!(l.display().to_string(), "<locked>");
+}
+
+#[test]
+fn test_display_poisoned() {
+    let m = Arc::new(Mutex::new(1u32));
+    let l = Arc::new(RwLock::new(1u32));
+
+    let (m2, l2) = (m.clone(), l.clone());
+    let _ = std::thread::spawn(move || {
+        let _m = m2.lock().unwrap();
+        let _l = l2.write().unwrap();
+        panic!("poison");
+    })
+    .join();
+
+    assert_eq!(m.display().to_string(), "<poisoned>");
+    assert_eq!(l.display().to_string(), "<poisoned>");
+}
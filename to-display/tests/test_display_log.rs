@@ -0,0 +1,75 @@
+#![cfg(all(feature = "log", feature = "std"))]
+
+use std::cell::Cell;
+use std::sync::Mutex;
+
+use log::kv;
+use to_display::display_fn;
+use to_display::display_kv;
+use to_display::DisplayConfig;
+
+/// Collects records as `msg k=v`, and ignores the ones with target `ignored`.
+struct Capture(Mutex<Vec<String>>);
+
+static LOGGER: Capture = Capture(Mutex::new(Vec::new()));
+
+impl log::Log for Capture {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        if record.target() == "ignored" {
+            return;
+        }
+
+        let mut line = record.args().to_string();
+        record.key_values().visit(&mut KvWriter(&mut line)).unwrap();
+        self.0.lock().unwrap().push(line);
+    }
+
+    fn flush(&self) {}
+}
+
+struct KvWriter<'a>(&'a mut String);
+
+impl<'kvs> kv::VisitSource<'kvs> for KvWriter<'_> {
+    fn visit_pair(&mut self, key: kv::Key<'kvs>, value: kv::Value<'kvs>) -> Result<(), kv::Error> {
+        self.0.push_str(&format!(" {}={}", key, value));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_display_kv() {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(log::LevelFilter::Info);
+
+    let ids = vec![Some(1), None, Some(3)];
+    log::info!(ids = display_kv(&ids).limit_items(2).verbose(); "request");
+    log::info!(ids = display_kv(&ids[..]); "slice");
+
+    // The value is formatted only when the logger writes the record.
+    let count = Cell::new(0);
+    let d = display_fn(|f, ctx| {
+        count.set(count.get() + 1);
+        write!(f, "verbose={}", ctx.verbose())
+    });
+
+    log::debug!(x = display_kv(&d); "filtered by level");
+    log::info!(target: "ignored", x = display_kv(&d); "ignored by logger");
+    assert_eq!(count.get(), 0);
+
+    log::info!(x = display_kv(&d).verbose(); "written");
+    assert_eq!(count.get(), 1);
+
+    let lines = std::mem::take(&mut *LOGGER.0.lock().unwrap());
+    assert_eq!(
+        lines,
+        vec![
+            "request ids=[Some(1), None, ...]",
+            "slice ids=[1, -, 3]",
+            "written x=verbose=true",
+        ]
+    );
+}
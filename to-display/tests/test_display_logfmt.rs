@@ -0,0 +1,167 @@
+#![cfg(feature = "alloc")]
+
+use std::cell::Cell;
+use std::collections::BTreeMap;
+
+use to_display::display_fn;
+use to_display::DisplayConfig;
+use to_display::OutputStyle;
+use to_display::ToDisplay;
+
+#[derive(ToDisplay)]
+#[to_display(fields)]
+struct Request {
+    id: u64,
+    path: &'static str,
+    peer: Peer,
+    user: Option<&'static str>,
+    tags: Vec<u8>,
+}
+
+#[derive(ToDisplay)]
+#[to_display(fields)]
+struct Peer {
+    addr: &'static str,
+    port: u16,
+}
+
+#[derive(ToDisplay)]
+#[to_display(fields)]
+struct Empty {}
+
+#[derive(ToDisplay)]
+#[to_display(fields)]
+struct Raw {
+    r#type: u8,
+}
+
+fn request() -> Request {
+    Request {
+        id: 7,
+        path: "/a b",
+        peer: Peer {
+            addr: "10.0.0.1",
+            port: 80,
+        },
+        user: None,
+        tags: vec![1, 2],
+    }
+}
+
+#[test]
+fn test_display_struct_plain() {
+    assert_eq!(
+        request().display().to_string(),
+        "Request { id: 7, path: /a b, peer: Peer { addr: 10.0.0.1, port: 80 }, user: -, tags: [1, 2] }"
+    );
+    assert_eq!(Empty {}.display().to_string(), "Empty {}");
+}
+
+#[test]
+fn test_display_struct_logfmt() {
+    let r = request();
+    assert_eq!(
+        r.display().logfmt().to_string(),
+        r#"id=7 path="/a b" peer.addr=10.0.0.1 peer.port=80 user=- tags="[1, 2]""#
+    );
+
+    let r = Request {
+        user: Some("bob"),
+        ..request()
+    };
+    assert_eq!(
+        r.display()
+            .with_output_style(OutputStyle::Logfmt)
+            .verbose()
+            .to_string(),
+        r#"id=7 path="/a b" peer.addr=10.0.0.1 peer.port=80 user=Some(bob) tags="[1, 2]""#
+    );
+
+    assert_eq!(Empty {}.display().logfmt().to_string(), "");
+
+    // A `Some` of a structured value is flattened the same way as the inner value.
+    let peer = Peer {
+        addr: "::1",
+        port: 22,
+    };
+    let m = BTreeMap::from([("p", Some(peer)), ("q", None)]);
+    assert_eq!(m.display().logfmt().to_string(), "p.addr=::1 p.port=22 q=-");
+}
+
+#[test]
+fn test_display_logfmt_quoting() {
+    let m = BTreeMap::from([
+        ("empty", ""),
+        ("eq", "a=b"),
+        ("quote", "say \"hi\""),
+        ("newline", "a\nb\\c"),
+        ("control", "\u{1b}[0m"),
+        ("plain", "ok"),
+        ("bad key", "x"),
+    ]);
+
+    assert_eq!(
+        m.display().logfmt().to_string(),
+        [
+            r#"bad_key=x"#,
+            r#"control="\u001b[0m""#,
+            r#"empty="""#,
+            r#"eq="a=b""#,
+            r#"newline="a\nb\\c""#,
+            r#"plain=ok"#,
+            r#"quote="say \"hi\"""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn test_display_btreemap_logfmt_nested() {
+    let m = BTreeMap::from([
+        ("a", BTreeMap::from([("x", 1), ("y", 2)])),
+        ("b", BTreeMap::new()),
+    ]);
+    assert_eq!(m.display().logfmt().to_string(), "a.x=1 a.y=2");
+    // Left out entries are marked with a `...` field.
+    assert_eq!(
+        m.display().logfmt().limit_items(1).to_string(),
+        "a.x=1 a....=... ...=..."
+    );
+
+    // Values that are not structured are displayed as in the plain style.
+    assert_eq!(vec![1, 2].display().logfmt().to_string(), "[1, 2]");
+}
+
+#[test]
+fn test_display_logfmt_formats_value_once() {
+    // A value that changes each time it is formatted is quoted according to what is written.
+    let calls = Cell::new(0);
+    let v = display_fn(|f, _ctx| {
+        calls.set(calls.get() + 1);
+        if calls.get() == 1 {
+            write!(f, "a b")
+        } else {
+            write!(f, "ab")
+        }
+    });
+    let m = BTreeMap::from([("v", v)]);
+    assert_eq!(m.display().logfmt().to_string(), r#"v="a b""#);
+    assert_eq!(calls.get(), 1);
+
+    // A value too long to be buffered is always quoted.
+    let long = "x".repeat(300);
+    let m = BTreeMap::from([("short", "x".repeat(256)), ("long", long.clone())]);
+    assert_eq!(
+        m.display().logfmt().limit_str_len(1000).to_string(),
+        format!(r#"long="{}" short={}"#, long, "x".repeat(256))
+    );
+}
+
+#[test]
+fn test_display_struct_raw_field_name() {
+    // Raw identifiers are displayed without the `r#` prefix, the same as serde.
+    let r = Raw { r#type: 1 };
+    assert_eq!(r.display().to_string(), "Raw { type: 1 }");
+    assert_eq!(r.display().logfmt().to_string(), "type=1");
+    assert_eq!(r.display().json().to_string(), r#"{"type":1}"#);
+}
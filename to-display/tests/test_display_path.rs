@@ -0,0 +1,117 @@
+#![cfg(all(feature = "std", unix))]
+
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::ffi::OsStringExt;
+use std::path::Path;
+use std::path::PathBuf;
+
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_path() {
+    let p = Path::new("/foo/bar.rs");
+    assert_eq!(ToDisplay::display(p).to_string(), "/foo/bar.rs");
+    assert_eq!(
+        ToDisplay::display(p).quote_paths().to_string(),
+        r#""/foo/bar.rs""#
+    );
+
+    let p = PathBuf::from("a\"b\\c");
+    assert_eq!(ToDisplay::display(&p).to_string(), "a\"b\\c");
+    assert_eq!(
+        ToDisplay::display(&p).quote_paths().to_string(),
+        r#""a\"b\\c""#
+    );
+
+    // A line break can not end a log line.
+    let p = PathBuf::from("a\nb\tc\u{1b}");
+    assert_eq!(
+        ToDisplay::display(&p).quote_paths().to_string(),
+        r#""a\nb\tc\u{1b}""#
+    );
+}
+
+#[test]
+fn test_display_path_non_utf8() {
+    let p = Path::new(OsStr::from_bytes(b"/tmp/a\xffb\xfe"));
+
+    assert_eq!(ToDisplay::display(p).to_string(), "/tmp/a\u{FFFD}b\u{FFFD}");
+    assert_eq!(
+        ToDisplay::display(p).escape_non_utf8().to_string(),
+        r"/tmp/a\xffb\xfe"
+    );
+    assert_eq!(
+        ToDisplay::display(p)
+            .escape_non_utf8()
+            .quote_paths()
+            .to_string(),
+        r#""/tmp/a\xffb\xfe""#
+    );
+}
+
+#[test]
+fn test_display_path_shorten() {
+    let p = Path::new("/very/long/path/to/some/file.rs");
+
+    assert_eq!(
+        ToDisplay::display(p).limit_path_len(100).to_string(),
+        "/very/long/path/to/some/file.rs"
+    );
+    assert_eq!(
+        ToDisplay::display(p).limit_path_len(20).to_string(),
+        "/very/…/some/file.rs"
+    );
+    assert_eq!(
+        ToDisplay::display(p).limit_path_len(15).to_string(),
+        "/very/…/file.rs"
+    );
+
+    // The first and the last component are always kept.
+    assert_eq!(
+        ToDisplay::display(p).limit_path_len(3).to_string(),
+        "/very/…/file.rs"
+    );
+
+    let p = Path::new("very/long/path/to/some/file.rs");
+    assert_eq!(
+        ToDisplay::display(p).limit_path_len(19).to_string(),
+        "very/…/some/file.rs"
+    );
+    assert_eq!(
+        ToDisplay::display(p)
+            .limit_path_len(19)
+            .quote_paths()
+            .to_string(),
+        r#""very/…/some/file.rs""#
+    );
+}
+
+#[test]
+fn test_display_os_str() {
+    let s = OsStr::new("foo");
+    assert_eq!(ToDisplay::display(s).to_string(), "foo");
+    assert_eq!(ToDisplay::display(s).quote_paths().to_string(), r#""foo""#);
+
+    let s = OsString::from_vec(b"a\x80".to_vec());
+    assert_eq!(ToDisplay::display(&s).to_string(), "a\u{FFFD}");
+    assert_eq!(
+        ToDisplay::display(&s).escape_non_utf8().to_string(),
+        r"a\x80"
+    );
+
+    // OS strings are not paths and are never shortened.
+    let s = OsStr::new("/very/long/path/to/some/file.rs");
+    assert_eq!(
+        ToDisplay::display(s).limit_path_len(3).to_string(),
+        "/very/long/path/to/some/file.rs"
+    );
+}
+
+#[test]
+fn test_display_vec_of_paths() {
+    let v = vec![PathBuf::from("/a"), PathBuf::from("/b")];
+    assert_eq!(v.display().quote_paths().to_string(), r#"["/a", "/b"]"#);
+}
@@ -0,0 +1,59 @@
+use std::ops::Bound;
+
+use to_display::DisplayBounds;
+use to_display::DisplayConfig;
+use to_display::RangeFormat;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_range_interval() {
+    assert_eq!((3..7).display().to_string(), "[3, 7)");
+    assert_eq!((3..=7).display().to_string(), "[3, 7]");
+    assert_eq!((3..).display().to_string(), "[3, +∞)");
+    assert_eq!((..7).display().to_string(), "(-∞, 7)");
+    assert_eq!((..=7).display().to_string(), "(-∞, 7]");
+    assert_eq!((..).display().to_string(), "(-∞, +∞)");
+
+    let b = DisplayBounds(Bound::Excluded(3), Bound::Included(7));
+    assert_eq!(b.display().to_string(), "(3, 7]");
+
+    let b: DisplayBounds<u64> = (Bound::Unbounded, Bound::Unbounded).into();
+    assert_eq!(b.display().use_interval_ranges().to_string(), "(-∞, +∞)");
+}
+
+#[test]
+fn test_display_range_rust() {
+    assert_eq!((3..7).display().use_rust_ranges().to_string(), "3..7");
+    assert_eq!((3..=7).display().use_rust_ranges().to_string(), "3..=7");
+    assert_eq!((3..).display().use_rust_ranges().to_string(), "3..");
+    assert_eq!((..7).display().use_rust_ranges().to_string(), "..7");
+    assert_eq!((..=7).display().use_rust_ranges().to_string(), "..=7");
+    assert_eq!((..).display().use_rust_ranges().to_string(), "..");
+
+    let b = DisplayBounds(Bound::Included(3), Bound::Excluded(7));
+    assert_eq!(
+        b.display().with_range_format(RangeFormat::Rust).to_string(),
+        "3..7"
+    );
+
+    let b = DisplayBounds(Bound::Excluded(3), Bound::Included(7));
+    assert_eq!(
+        b.display().use_rust_ranges().to_string(),
+        "(Excluded(3), Included(7))"
+    );
+}
+
+#[test]
+fn test_display_range_nested() {
+    assert_eq!(
+        (Some(1)..Some(2)).display().verbose().to_string(),
+        "[Some(1), Some(2))"
+    );
+}
+
+#[test]
+fn test_display_bound() {
+    assert_eq!(Bound::Included(3).display().to_string(), "Included(3)");
+    assert_eq!(Bound::Excluded("a").display().to_string(), "Excluded(a)");
+    assert_eq!(Bound::<u8>::Unbounded.display().to_string(), "Unbounded");
+}
@@ -1,3 +1,4 @@
+use to_display::display_result;
 use to_display::DisplayConfig;
 use to_display::ToDisplay;
 
@@ -15,3 +16,107 @@ fn test_display_result() {
     let result: Result<Option<i32>, &str> = Ok(Some(42));
     assert_eq!(result.display().verbose().to_string(), "Ok(Some(42))");
 }
+
+#[test]
+fn test_display_result_bare_ok() {
+    let result: Result<i32, &str> = Ok(42);
+    assert_eq!(result.display().bare_ok().to_string(), "42");
+    assert_eq!(result.display().bare_ok().verbose().to_string(), "Ok(42)");
+
+    let result: Result<i32, &str> = Err("error");
+    assert_eq!(result.display().bare_ok().to_string(), "Err(error)");
+}
+
+#[test]
+fn test_display_result_to_display_error() {
+    // The error only needs to be `ToDisplay`, and it inherits the context.
+    let result: Result<i32, Option<u64>> = Err(Some(3));
+    assert_eq!(result.display().to_string(), "Err(3)");
+    assert_eq!(result.display().verbose().to_string(), "Err(Some(3))");
+
+    let result: Result<i32, Option<u64>> = Err(None);
+    assert_eq!(result.display().to_string(), "Err(-)");
+}
+
+#[test]
+fn test_display_result_std_error() {
+    let result: Result<u8, std::num::ParseIntError> = "x".parse();
+    assert_eq!(
+        result.display().to_string(),
+        "Err(invalid digit found in string)"
+    );
+
+    let result: Result<u8, std::num::ParseIntError> = "7".parse();
+    assert_eq!(result.display().bare_ok().to_string(), "7");
+}
+
+#[derive(Debug)]
+struct LoadError {
+    path: &'static str,
+    source: std::num::ParseIntError,
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to load {}", self.path)
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+to_display::impl_to_display_error!(LoadError);
+
+#[test]
+fn test_display_result_user_error() {
+    let result: Result<u8, LoadError> = "x".parse().map_err(|source| LoadError {
+        path: "a.toml",
+        source,
+    });
+
+    assert_eq!(result.display().to_string(), "Err(failed to load a.toml)");
+    assert_eq!(
+        result.display().verbose().to_string(),
+        "Err(failed to load a.toml: invalid digit found in string)"
+    );
+    assert_eq!(
+        result.display().json().to_string(),
+        r#"{"Err":"failed to load a.toml"}"#
+    );
+}
+
+#[test]
+fn test_display_result_third_party_error() {
+    // `serde_json::Error` is not `ToDisplay`, and another crate can not implement it.
+    let result: Result<u8, serde_json::Error> = serde_json::from_str("x");
+    assert_eq!(
+        display_result(&result).to_string(),
+        "Err(expected value at line 1 column 1)"
+    );
+    assert_eq!(
+        display_result(&result).json().to_string(),
+        r#"{"Err":"expected value at line 1 column 1"}"#
+    );
+
+    let result: Result<u8, serde_json::Error> = serde_json::from_str("7");
+    assert_eq!(display_result(&result).to_string(), "Ok(7)");
+    assert_eq!(display_result(&result).bare_ok().to_string(), "7");
+
+    // Nested in another displayer, it inherits the context.
+    let results = [display_result(&result)];
+    assert_eq!(results[..].display().bare_ok().to_string(), "[7]");
+
+    // As a `&dyn Error`, the error is `ToDisplay` and its causes are displayed in verbose mode.
+    let result: Result<u8, LoadError> = "x".parse().map_err(|source| LoadError {
+        path: "a.toml",
+        source,
+    });
+    let result = result.as_ref().map_err(|e| e as &dyn std::error::Error);
+    assert_eq!(
+        result.display().verbose().to_string(),
+        "Err(failed to load a.toml: invalid digit found in string)"
+    );
+}
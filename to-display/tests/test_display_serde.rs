@@ -0,0 +1,288 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use serde::ser;
+use serde::Serialize;
+use serde::Serializer;
+use to_display::display_serde;
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[derive(Serialize)]
+struct Request {
+    id: u64,
+    user: Option<String>,
+    token: &'static str,
+    tags: Vec<&'static str>,
+    headers: BTreeMap<&'static str, &'static str>,
+}
+
+#[derive(Serialize)]
+struct Id(u64);
+
+#[derive(Serialize)]
+struct Empty {}
+
+#[derive(Serialize)]
+struct Marker;
+
+#[derive(Serialize)]
+struct Point(i32, i32);
+
+#[derive(Serialize)]
+enum Event {
+    Start,
+    Stop(u32),
+    Move(i32, i32),
+    Rename { from: String, to: String },
+}
+
+#[derive(Serialize)]
+struct Tree {
+    name: &'static str,
+    children: Vec<Tree>,
+}
+
+struct Failing;
+
+impl Serialize for Failing {
+    fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(ser::Error::custom("not serializable"))
+    }
+}
+
+fn request() -> Request {
+    Request {
+        id: 7,
+        user: None,
+        token: "s3cr3t",
+        tags: vec!["a", "b", "c"],
+        headers: BTreeMap::from([("host", "example.com"), ("x-id", "1")]),
+    }
+}
+
+#[test]
+fn test_display_serde() {
+    let r = request();
+    assert_eq!(
+        display_serde(&r).to_string(),
+        "Request { id: 7, user: -, token: s3cr3t, tags: [a, b, c], headers: {host: example.com, x-id: 1} }"
+    );
+    assert_eq!(
+        display_serde(&r).verbose().to_string(),
+        "Request { id: 7, user: None, token: s3cr3t, tags: [a, b, c], headers: {host: example.com, x-id: 1} }"
+    );
+
+    let r = Request {
+        user: Some("alice".to_string()),
+        ..request()
+    };
+    assert_eq!(display_serde(&r.user).verbose().to_string(), "Some(alice)");
+
+    assert_eq!(display_serde(&Id(3)).to_string(), "3");
+    assert_eq!(display_serde(&Empty {}).to_string(), "Empty {}");
+    assert_eq!(display_serde(&Marker).to_string(), "Marker");
+    assert_eq!(display_serde(&Point(1, -2)).to_string(), "Point(1, -2)");
+    assert_eq!(display_serde(&(1, "a")).to_string(), "(1, a)");
+    assert_eq!(display_serde(&()).to_string(), "()");
+}
+
+#[test]
+fn test_display_serde_enum() {
+    assert_eq!(display_serde(&Event::Start).to_string(), "Start");
+    assert_eq!(display_serde(&Event::Stop(3)).to_string(), "Stop(3)");
+    assert_eq!(display_serde(&Event::Move(1, 2)).to_string(), "Move(1, 2)");
+    assert_eq!(
+        display_serde(&Event::Rename {
+            from: "a".to_string(),
+            to: "b".to_string(),
+        })
+        .to_string(),
+        "Rename { from: a, to: b }"
+    );
+}
+
+#[test]
+fn test_display_serde_limit_items() {
+    let r = request();
+    assert_eq!(
+        display_serde(&r).limit_items(1).to_string(),
+        "Request { id: 7, user: -, token: s3cr3t, tags: [a, ...], headers: {host: example.com, ...} }"
+    );
+    assert_eq!(display_serde(&r.tags).limit_items(0).to_string(), "[, ...]");
+
+    // Tuples are not collections and are not truncated.
+    assert_eq!(
+        display_serde(&(1, 2, 3)).limit_items(1).to_string(),
+        "(1, 2, 3)"
+    );
+
+    // Brackets and separators follow the context.
+    assert_eq!(
+        display_serde(&r.headers)
+            .with_map_brackets("", "")
+            .with_separator(" ")
+            .with_key_value_separator("=")
+            .to_string(),
+        "host=example.com x-id=1"
+    );
+}
+
+#[test]
+fn test_display_serde_limit_depth() {
+    let t = Tree {
+        name: "root",
+        children: vec![Tree {
+            name: "a",
+            children: vec![Tree {
+                name: "b",
+                children: vec![],
+            }],
+        }],
+    };
+
+    assert_eq!(
+        display_serde(&t).limit_depth(3).to_string(),
+        "Tree { name: root, children: [Tree { name: a, children: ... }] }"
+    );
+    assert_eq!(
+        display_serde(&t).limit_depth(4).to_string(),
+        "Tree { name: root, children: [Tree { name: a, children: [...] }] }"
+    );
+    assert_eq!(display_serde(&t).limit_depth(0).to_string(), "...");
+    assert_eq!(
+        display_serde(&t).limit_depth(1).json().to_string(),
+        r#"{"name":"root","children":"..."}"#
+    );
+}
+
+#[test]
+fn test_display_serde_redact_fields() {
+    let r = request();
+    assert_eq!(
+        display_serde(&r)
+            .redact_fields(&["token", "headers"])
+            .to_string(),
+        "Request { id: 7, user: -, token: <redacted>, tags: [a, b, c], headers: <redacted> }"
+    );
+    assert_eq!(
+        display_serde(&r)
+            .redact_fields(&["token", "tags", "headers"])
+            .json()
+            .to_string(),
+        r#"{"id":7,"user":null,"token":"<redacted>","tags":"<redacted>","headers":"<redacted>"}"#
+    );
+
+    // Map keys are not fields.
+    assert_eq!(
+        display_serde(&r.headers)
+            .redact_fields(&["host"])
+            .to_string(),
+        "{host: example.com, x-id: 1}"
+    );
+}
+
+#[test]
+fn test_display_serde_json() {
+    let r = request();
+    assert_eq!(
+        display_serde(&r).json().to_string(),
+        r#"{"id":7,"user":null,"token":"s3cr3t","tags":["a","b","c"],"headers":{"host":"example.com","x-id":"1"}}"#
+    );
+    assert_eq!(
+        display_serde(&r).json().limit_items(1).to_string(),
+        r#"{"id":7,"user":null,"token":"s3cr3t","tags":["a","..."],"headers":{"host":"example.com","...":"..."}}"#
+    );
+    assert_eq!(
+        display_serde(&r.tags).json().limit_items(0).to_string(),
+        r#"["..."]"#
+    );
+
+    assert_eq!(
+        display_serde(&BTreeMap::from([(1, "a\"b")]))
+            .json()
+            .to_string(),
+        r#"{"1":"a\"b"}"#
+    );
+    assert_eq!(
+        display_serde(&Event::Start).json().to_string(),
+        r#""Start""#
+    );
+    assert_eq!(
+        display_serde(&Event::Stop(3)).json().to_string(),
+        r#"{"Stop":3}"#
+    );
+    assert_eq!(
+        display_serde(&Event::Move(1, 2)).json().to_string(),
+        r#"{"Move":[1,2]}"#
+    );
+    assert_eq!(
+        display_serde(&Event::Rename {
+            from: "a".to_string(),
+            to: "b".to_string(),
+        })
+        .json()
+        .to_string(),
+        r#"{"Rename":{"from":"a","to":"b"}}"#
+    );
+    assert_eq!(display_serde(&Marker).json().to_string(), "null");
+    assert_eq!(display_serde(&Point(1, 2)).json().to_string(), "[1,2]");
+}
+
+#[test]
+fn test_display_serde_context() {
+    let r = request();
+
+    // Strings and bytes follow the context the same way as `ToDisplay` values.
+    assert_eq!(
+        display_serde(&"a\nb").escape_strings().to_string(),
+        r#""a\nb""#
+    );
+    assert_eq!(
+        display_serde(&Bytes(b"ab")).use_hex_bytes().to_string(),
+        "6162"
+    );
+
+    // Strings are truncated, but not map keys.
+    assert_eq!(
+        display_serde(&r.headers).limit_str_len(2).to_string(),
+        "{host: ex..., x-id: 1}"
+    );
+    assert_eq!(
+        display_serde(&r.headers)
+            .limit_str_len(2)
+            .json()
+            .to_string(),
+        r#"{"host":"ex...","x-id":"1"}"#
+    );
+
+    // Nested in a `ToDisplay` value, it inherits the context of the parent.
+    let v = vec![display_serde(&r.tags)];
+    assert_eq!(v.display().limit_items(2).to_string(), "[[a, b, ...]]");
+}
+
+#[test]
+fn test_display_serde_error() {
+    assert_eq!(
+        display_serde(&Failing).to_string(),
+        "<error: not serializable>"
+    );
+    assert_eq!(
+        display_serde(&(1, Failing)).to_string(),
+        "(1, <error: not serializable>"
+    );
+    assert_eq!(
+        display_serde(&Failing).json().to_string(),
+        r#""<error: not serializable>""#
+    );
+}
+
+/// Serializes with `serialize_bytes`, instead of as a sequence of `u8`.
+struct Bytes(&'static [u8]);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
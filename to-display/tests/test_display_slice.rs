@@ -17,8 +17,49 @@ fn test_display_slice() {
     assert_eq!(foo.display().to_string(), "[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 1, 2, ...]");
 }
 
+#[test]
+fn test_display_slice_time_settings() {
+    // Time settings are available without a time backend, and do not change other values.
+    let foo = [1, 2, 3];
+
+    assert_eq!(foo.display().use_utc_time().to_string(), "[1, 2, 3]");
+    assert_eq!(foo.display().use_local_time().to_string(), "[1, 2, 3]");
+    assert_eq!(foo.display().use_short_time().to_string(), "[1, 2, 3]");
+    assert_eq!(foo.display().use_full_time().to_string(), "[1, 2, 3]");
+    assert_eq!(
+        foo.display().with_time_format("%H").to_string(),
+        "[1, 2, 3]"
+    );
+}
+
+#[cfg(feature = "alloc")]
 #[test]
 fn test_display_vec() {
     let v = vec![1];
     assert_eq!(v.display().to_string(), "[1]");
 }
+
+#[test]
+fn test_display_slice_delimiters() {
+    let foo = [1, 2, 3];
+
+    assert_eq!(
+        foo.display()
+            .with_list_brackets("", "")
+            .with_separator(",")
+            .to_string(),
+        "1,2,3"
+    );
+    assert_eq!(
+        foo.display()
+            .with_list_brackets("", "")
+            .with_separator(",")
+            .limit_items(2)
+            .to_string(),
+        "1,2,..."
+    );
+    assert_eq!(
+        foo.display().with_list_brackets("(", ")").to_string(),
+        "(1, 2, 3)"
+    );
+}
@@ -0,0 +1,74 @@
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_str() {
+    let s = "a\"b'\n\u{1b}[31m";
+    assert_eq!(s.display().to_string(), "a\"b'\n\u{1b}[31m");
+    assert_eq!(
+        s.display().escape_strings().to_string(),
+        r#""a\"b'\n\u{1b}[31m""#
+    );
+
+    // Formatting options are passed through.
+    assert_eq!(format!("{:>4}", "ab".display()), "  ab");
+}
+
+#[test]
+fn test_display_str_limit_len() {
+    let s = "héllo\n";
+    assert_eq!(s.display().limit_str_len(2).to_string(), "hé...");
+    assert_eq!(
+        s.display().limit_str_len(3).escape_strings().to_string(),
+        r#""hél"..."#
+    );
+    assert_eq!(
+        s.display().limit_str_len(5).json().to_string(),
+        r#""héllo...""#
+    );
+    assert_eq!(s.display().limit_str_len(6).to_string(), "héllo\n");
+    assert_eq!(s.display().limit_str_len(0).to_string(), "...");
+
+    // The limit applies to nested strings.
+    assert_eq!(
+        ["abc", "d"].display().limit_str_len(1).to_string(),
+        "[a..., d]"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_display_string() {
+    let s = String::from("line1\nline2");
+    assert_eq!(s.display().to_string(), "line1\nline2");
+    assert_eq!(
+        s.display().escape_strings().to_string(),
+        r#""line1\nline2""#
+    );
+
+    let v = vec![String::from("a\tb"), String::from("c")];
+    assert_eq!(v.display().escape_strings().to_string(), r#"["a\tb", "c"]"#);
+}
+
+#[test]
+fn test_display_char() {
+    assert_eq!('a'.display().to_string(), "a");
+    assert_eq!('a'.display().escape_strings().to_string(), "'a'");
+    assert_eq!('\n'.display().escape_strings().to_string(), r"'\n'");
+    assert_eq!('\''.display().escape_strings().to_string(), r"'\''");
+}
+
+#[test]
+fn test_display_c_str() {
+    let s = c"a'\"\n\xff";
+    assert_eq!(s.display().to_string(), "a'\"\n\u{FFFD}");
+    assert_eq!(s.display().escape_strings().to_string(), r#""a'\"\n\xff""#);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_display_c_string() {
+    let s = std::ffi::CString::new(b"ab\x1b".to_vec()).unwrap();
+    assert_eq!(s.display().to_string(), "ab\u{1b}");
+    assert_eq!(s.display().escape_strings().to_string(), r#""ab\u{1b}""#);
+}
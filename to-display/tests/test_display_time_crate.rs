@@ -0,0 +1,85 @@
+#![cfg(feature = "time-crate")]
+
+use std::time::Duration;
+use std::time::Instant;
+use std::time::UNIX_EPOCH;
+
+use time::macros::datetime;
+use time::UtcOffset;
+use to_display::scoped_clock;
+use to_display::DisplayConfig;
+use to_display::FixedClock;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_offset_date_time() {
+    let t = datetime!(2024-12-28 23:33:20.123456 +08:00);
+
+    assert_eq!(t.display().use_utc_time().to_string(), "15:33:20.123456");
+    assert_eq!(
+        t.display()
+            .use_fixed_offset(UtcOffset::from_hms(-5, 0, 0).unwrap().try_into().unwrap())
+            .use_rfc3339_time()
+            .to_string(),
+        "2024-12-28T10:33:20.123456-05:00"
+    );
+    assert_eq!(
+        t.display().use_utc_time().use_rfc2822_time().to_string(),
+        "Sat, 28 Dec 2024 15:33:20 +0000"
+    );
+    assert_eq!(t.display().use_unix_millis().to_string(), "1735400000123");
+
+    // `time` accepts offsets of up to 26 hours, which are rejected instead of clamped.
+    let offset = UtcOffset::from_hms(25, 0, 0).unwrap();
+    let err = to_display::UtcOffset::try_from(offset).unwrap_err();
+    assert_eq!(err.seconds(), 90000);
+}
+
+#[test]
+fn test_display_time_duration() {
+    let d = time::Duration::milliseconds(1500);
+    assert_eq!(d.display().to_string(), d.to_string());
+}
+
+#[test]
+fn test_display_instant_with_time_crate() {
+    let now = Instant::now();
+
+    // 2024-12-28T15:33:20Z
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(1_735_400_000))
+        .with_local_offset(to_display::UtcOffset::from_seconds(8 * 3600).unwrap());
+    let _clock = scoped_clock(clock);
+
+    assert_eq!(
+        now.display().use_full_time().to_string(),
+        "2024-12-28T23:33:20.000000Z+0800"
+    );
+}
+#[test]
+fn test_display_instant_with_time_crate_out_of_range() {
+    let now = Instant::now();
+
+    // 9999-12-30T00:00:00Z, and one year later.
+    let clock = FixedClock::new(now, UNIX_EPOCH + Duration::from_secs(253_402_128_000));
+    let _clock = scoped_clock(clock);
+    let later = now + Duration::from_secs(365 * 86_400);
+
+    assert_eq!(
+        now.display().use_utc_time().use_rfc3339_time().to_string(),
+        "9999-12-30T00:00:00.000000Z"
+    );
+
+    // Out of the range of `time` and `jiff`, unless chrono is enabled.
+    #[cfg(not(feature = "std-time"))]
+    {
+        assert_eq!(later.display().use_utc_time().to_string(), "<out of range>");
+        assert_eq!(
+            later.display().use_utc_time().json().to_string(),
+            r#""<out of range>""#
+        );
+    }
+    assert_eq!(
+        later.display().use_unix_seconds().to_string(),
+        "253433664000"
+    );
+}
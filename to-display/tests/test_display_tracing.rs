@@ -0,0 +1,117 @@
+#![cfg(feature = "tracing")]
+
+use std::io;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use to_display::display_field;
+use to_display::ContextLayer;
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+
+/// Collects the output of a `fmt` layer.
+#[derive(Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl Output {
+    fn take(&self) -> String {
+        let buf = std::mem::take(&mut *self.0.lock().unwrap());
+        String::from_utf8(buf).unwrap()
+    }
+}
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Output {
+    type Writer = Output;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+fn fmt_layer<S>(output: &Output) -> impl tracing_subscriber::Layer<S>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    tracing_subscriber::fmt::layer()
+        .with_writer(output.clone())
+        .without_time()
+        .with_ansi(false)
+        .with_level(false)
+        .with_target(false)
+}
+
+#[test]
+fn test_display_field() {
+    let output = Output::default();
+    let subscriber = tracing_subscriber::registry()
+        .with(ContextLayer::new().limit_items(2).verbose())
+        .with(fmt_layer(&output));
+
+    let ids = vec![Some(1), None, Some(3)];
+
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!(ids = %display_field(&ids), "a");
+        tracing::info!(ids = ?display_field(&ids), "b");
+
+        // Options set on the field override the ones of the layer.
+        tracing::info!(ids = %display_field(&ids).limit_items(1), "c");
+
+        // A displayer returned by `display()` does not use the context of the layer.
+        tracing::info!(ids = %ids.display(), "d");
+
+        let span = tracing::info_span!("span", ids = %display_field(&ids));
+        span.in_scope(|| tracing::info!("e"));
+    });
+
+    assert_eq!(
+        output.take(),
+        [
+            "a ids=[Some(1), None, ...]",
+            "b ids=[Some(1), None, ...]",
+            "c ids=[Some(1), ...]",
+            "d ids=[1, -, 3]",
+            "span{ids=[Some(1), None, ...]}: e",
+            "",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_display_field_without_layer() {
+    let output = Output::default();
+    let subscriber = tracing_subscriber::registry().with(fmt_layer(&output));
+
+    tracing::subscriber::with_default(subscriber, || {
+        assert_eq!(ContextLayer::current().map(|c| c.max_items()), None);
+        tracing::info!(ids = %display_field(&[1, 2, 3][..]).limit_items(2), "a");
+    });
+
+    assert_eq!(output.take(), "a ids=[1, 2, ...]\n");
+}
+
+#[test]
+fn test_context_layer_current() {
+    let layer = ContextLayer::new().limit_items(5);
+    assert_eq!(layer.context().max_items(), 5);
+
+    let subscriber = tracing_subscriber::registry().with(layer);
+    tracing::subscriber::with_default(subscriber, || {
+        assert_eq!(ContextLayer::current().map(|c| c.max_items()), Some(5));
+    });
+
+    assert!(ContextLayer::current().is_none());
+}
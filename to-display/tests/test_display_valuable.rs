@@ -0,0 +1,197 @@
+#![cfg(all(feature = "valuable", feature = "std"))]
+
+use std::collections::BTreeMap;
+
+use to_display::display_valuable;
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+use valuable::Valuable;
+
+#[derive(Valuable)]
+struct Request {
+    id: u64,
+    user: Option<String>,
+    token: &'static str,
+    tags: Vec<&'static str>,
+    headers: BTreeMap<&'static str, &'static str>,
+}
+
+#[derive(Valuable)]
+struct Point(i32, i32);
+
+#[derive(Valuable)]
+enum Event {
+    Start,
+    Move(i32, i32),
+    Rename { from: String, to: String },
+}
+
+#[derive(Valuable)]
+struct Tree {
+    name: &'static str,
+    children: Vec<Tree>,
+}
+
+#[derive(ToDisplay)]
+#[to_display(fields, valuable)]
+struct Peer {
+    addr: &'static str,
+    port: u16,
+    tags: Vec<String>,
+}
+
+#[derive(ToDisplay)]
+#[to_display(fields, valuable)]
+struct Raw {
+    r#type: u8,
+}
+
+fn request() -> Request {
+    Request {
+        id: 7,
+        user: None,
+        token: "s3cr3t",
+        tags: vec!["a", "b", "c"],
+        headers: BTreeMap::from([("host", "example.com"), ("x-id", "1")]),
+    }
+}
+
+#[test]
+fn test_display_valuable() {
+    let r = request();
+    assert_eq!(
+        display_valuable(&r).to_string(),
+        "Request { id: 7, user: -, token: s3cr3t, tags: [a, b, c], headers: {host: example.com, x-id: 1} }"
+    );
+    assert_eq!(
+        display_valuable(&r).verbose().to_string(),
+        "Request { id: 7, user: None, token: s3cr3t, tags: [a, b, c], headers: {host: example.com, x-id: 1} }"
+    );
+
+    assert_eq!(display_valuable(&Point(1, -2)).to_string(), "Point(1, -2)");
+    assert_eq!(display_valuable(&(1, "a")).to_string(), "(1, a)");
+    assert_eq!(display_valuable(&Some(1.5)).to_string(), "1.5");
+
+    assert_eq!(display_valuable(&Event::Start).to_string(), "Start");
+    assert_eq!(
+        display_valuable(&Event::Move(1, 2)).to_string(),
+        "Move(1, 2)"
+    );
+    assert_eq!(
+        display_valuable(&Event::Rename {
+            from: "a".to_string(),
+            to: "b".to_string(),
+        })
+        .to_string(),
+        "Rename { from: a, to: b }"
+    );
+}
+
+#[test]
+fn test_display_valuable_context() {
+    let r = request();
+    assert_eq!(
+        display_valuable(&r)
+            .limit_items(1)
+            .redact_fields(&["token"])
+            .to_string(),
+        "Request { id: 7, user: -, token: <redacted>, tags: [a, ...], headers: {host: example.com, ...} }"
+    );
+    assert_eq!(
+        display_valuable(&r.headers)
+            .with_map_brackets("", "")
+            .with_separator(" ")
+            .with_key_value_separator("=")
+            .limit_str_len(4)
+            .to_string(),
+        "host=exam... x-id=1"
+    );
+
+    let t = Tree {
+        name: "root",
+        children: vec![Tree {
+            name: "a",
+            children: vec![],
+        }],
+    };
+    assert_eq!(
+        display_valuable(&t).limit_depth(2).to_string(),
+        "Tree { name: root, children: [...] }"
+    );
+
+    // Nested in a `ToDisplay` value, it inherits the context of the parent.
+    let v = vec![display_valuable(&r.tags)];
+    assert_eq!(v.display().limit_items(2).to_string(), "[[a, b, ...]]");
+}
+
+#[test]
+fn test_display_valuable_json() {
+    let r = request();
+    assert_eq!(
+        display_valuable(&r).json().to_string(),
+        r#"{"id":7,"user":null,"token":"s3cr3t","tags":["a","b","c"],"headers":{"host":"example.com","x-id":"1"}}"#
+    );
+    assert_eq!(
+        display_valuable(&r.tags).json().limit_items(0).to_string(),
+        r#"["..."]"#
+    );
+    assert_eq!(
+        display_valuable(&Event::Start).json().to_string(),
+        r#""Start""#
+    );
+    assert_eq!(
+        display_valuable(&Event::Move(1, 2)).json().to_string(),
+        r#"{"Move":[1,2]}"#
+    );
+    assert_eq!(
+        display_valuable(&Event::Rename {
+            from: "a".to_string(),
+            to: "b".to_string(),
+        })
+        .json()
+        .to_string(),
+        r#"{"Rename":{"from":"a","to":"b"}}"#
+    );
+    assert_eq!(display_valuable(&()).json().to_string(), "null");
+}
+
+#[test]
+fn test_derive_valuable() {
+    let p = Peer {
+        addr: "10.0.0.1",
+        port: 80,
+        tags: vec!["a".to_string(), "b".to_string()],
+    };
+
+    // Both directions display the same.
+    assert_eq!(
+        p.display().to_string(),
+        "Peer { addr: 10.0.0.1, port: 80, tags: [a, b] }"
+    );
+    assert_eq!(display_valuable(&p).to_string(), p.display().to_string());
+    assert_eq!(
+        display_valuable(&p).json().to_string(),
+        p.display().json().to_string()
+    );
+
+    let def = valuable::Structable::definition(&p);
+    assert_eq!(def.name(), "Peer");
+    assert_eq!(
+        def.fields().len(),
+        3,
+        "the fields are visible to valuable consumers"
+    );
+}
+
+#[test]
+fn test_derive_valuable_raw_field_name() {
+    let r = Raw { r#type: 1 };
+
+    let def = valuable::Structable::definition(&r);
+    match def.fields() {
+        valuable::Fields::Named(fields) => assert_eq!(fields[0].name(), "type"),
+        valuable::Fields::Unnamed(_) => panic!("named fields expected"),
+    }
+    assert_eq!(display_valuable(&r).to_string(), "Raw { type: 1 }");
+    assert_eq!(display_valuable(&r).json().to_string(), r#"{"type":1}"#);
+}
@@ -0,0 +1,35 @@
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::marker::PhantomData;
+use std::num::Saturating;
+use std::num::Wrapping;
+
+use to_display::DisplayConfig;
+use to_display::ToDisplay;
+
+#[test]
+fn test_display_forwarding_wrappers() {
+    assert_eq!(Wrapping(3u8).display().to_string(), "3");
+    assert_eq!(Saturating(-3i8).display().to_string(), "-3");
+    assert_eq!(Reverse(Some(3)).display().to_string(), "3");
+    assert_eq!(Reverse(Some(3)).display().verbose().to_string(), "Some(3)");
+}
+
+#[test]
+fn test_display_ordering() {
+    assert_eq!(Ordering::Less.display().to_string(), "Less");
+    assert_eq!(Ordering::Equal.display().to_string(), "Equal");
+    assert_eq!(Ordering::Greater.display().to_string(), "Greater");
+}
+
+#[test]
+fn test_display_unit_and_phantom_data() {
+    struct NotDisplay;
+
+    assert_eq!(().display().to_string(), "()");
+    assert_eq!(
+        PhantomData::<NotDisplay>.display().to_string(),
+        "PhantomData"
+    );
+    assert_eq!(Ok::<(), u8>(()).display().to_string(), "Ok(())");
+}